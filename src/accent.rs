@@ -13,6 +13,7 @@
 use crate::accent_data::{
     BHS_POETRY_RANK_MAP, POETRY_ACCENT_TABLE, PROSE_ACCENT_TABLE, PSEUDO_ACCENT_TABLE,
 };
+use crate::{Naming, TraditionKind};
 
 /// Gets accent information
 pub trait Accent: Copy + Sized {
@@ -52,6 +53,62 @@ pub trait Accent: Copy + Sized {
     fn word_stress(self) -> Option<WordStress> {
         self.details().word_stress
     }
+    /// allowed servi of the (disjunctive) Hebrew Accent
+    #[inline]
+    fn servi(self) -> Option<Servi> {
+        self.details().servi
+    }
+    /// names of the Hebrew Accent in the Jewish traditions
+    ///
+    /// Only accents of a single code point are named, the traditions name the
+    /// code point and not a combination of code points (e.g. Legarmeh).
+    #[inline]
+    fn traditions(self) -> &'static [Tradition] {
+        let code_points = self.details().code_points;
+        match code_points.secondary {
+            None => code_points.primary.traditions,
+            Some(_) => &[],
+        }
+    }
+    /// English name of the Hebrew Accent in a Jewish tradition
+    fn name_in(self, kind: TraditionKind) -> Option<&'static str> {
+        self.traditions()
+            .iter()
+            .find(|tradition| tradition.kind() == kind)
+            .map(|tradition| tradition.english_name())
+    }
+    /// Hebrew name of the Hebrew Accent in a Jewish tradition
+    fn hebrew_name_in(self, kind: TraditionKind) -> Option<&'static str> {
+        self.traditions()
+            .iter()
+            .find(|tradition| tradition.kind() == kind)
+            .map(|tradition| tradition.hebrew_name())
+    }
+    /// all English names of the Hebrew Accent, the BHS name first
+    fn all_names(self) -> Vec<(Naming, &'static str)> {
+        let mut names = vec![(Naming::Bhs, self.english_name())];
+        names.extend(self.traditions().iter().map(|tradition| {
+            (
+                Naming::Tradition(tradition.kind()),
+                tradition.english_name(),
+            )
+        }));
+        names
+    }
+    /// English name of the Hebrew Accent in the naming
+    fn display_name(self, naming: Naming) -> &'static str {
+        match naming {
+            Naming::Bhs => self.english_name(),
+            Naming::Tradition(kind) => self.name_in(kind).unwrap_or(self.english_name()),
+        }
+    }
+    /// Hebrew name of the Hebrew Accent in the naming
+    fn display_hebrew_name(self, naming: Naming) -> &'static str {
+        match naming {
+            Naming::Bhs => self.hebrew_name(),
+            Naming::Tradition(kind) => self.hebrew_name_in(kind).unwrap_or(self.hebrew_name()),
+        }
+    }
     /// number of UTF-8 code points of the Hebrew Accent
     fn code_points(self) -> u8;
 }
@@ -191,6 +248,7 @@ impl Accent for PseudoAccent {
 
 /// Hebrew Accent, either a Prose or Poetry accent
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HebrewAccent {
     /// TODO
     Prose(ProseAccent),
@@ -283,6 +341,37 @@ pub enum ProseAccent {
 impl ProseAccent {
     /// The total number of prose accents
     pub const COUNT: usize = 28;
+    /// All prose accents, in the order of the accent table
+    pub const ALL: [ProseAccent; Self::COUNT] = [
+        ProseAccent::Silluq,
+        ProseAccent::Atnach,
+        ProseAccent::Segolta,
+        ProseAccent::Shalshelet,
+        ProseAccent::ZaqephQaton,
+        ProseAccent::ZaqephGadol,
+        ProseAccent::Revia,
+        ProseAccent::Tiphcha,
+        ProseAccent::Zarqa,
+        ProseAccent::Pashta,
+        ProseAccent::Yetiv,
+        ProseAccent::Tevir,
+        ProseAccent::Geresh,
+        ProseAccent::Gershayim,
+        ProseAccent::Pazer,
+        ProseAccent::PazerGadol,
+        ProseAccent::TelishaGedolah,
+        ProseAccent::Legarmeh,
+        ProseAccent::Munach,
+        ProseAccent::Mahpakh,
+        ProseAccent::Merkha,
+        ProseAccent::MerkhaKephulah,
+        ProseAccent::Darga,
+        ProseAccent::Azla,
+        ProseAccent::TelishaQetannah,
+        ProseAccent::Galgal,
+        ProseAccent::Mayela,
+        ProseAccent::Meteg,
+    ];
     ///  TODO
     #[inline]
     pub fn relative_strength(self) -> u8 {
@@ -348,6 +437,32 @@ pub enum PoetryAccent {
 impl PoetryAccent {
     /// Total count of all poetry accents,including some 'non-accents'
     pub const COUNT: usize = 23;
+    /// All poetry accents, in the order of the accent table
+    pub const ALL: [PoetryAccent; Self::COUNT] = [
+        PoetryAccent::Silluq,
+        PoetryAccent::OlehWeYored,
+        PoetryAccent::Atnach,
+        PoetryAccent::ReviaGadol,
+        PoetryAccent::ReviaMugrash,
+        PoetryAccent::ShalsheletGadol,
+        PoetryAccent::Tsinnor,
+        PoetryAccent::ReviaQaton,
+        PoetryAccent::Dechi,
+        PoetryAccent::Pazer,
+        PoetryAccent::MehuppakhLegarmeh,
+        PoetryAccent::AzlaLegarmeh,
+        PoetryAccent::Munach,
+        PoetryAccent::Merkha,
+        PoetryAccent::Illuy,
+        PoetryAccent::Tarcha,
+        PoetryAccent::Galgal,
+        PoetryAccent::Mehuppakh,
+        PoetryAccent::Azla,
+        PoetryAccent::ShalsheletQetannah,
+        PoetryAccent::TsinnoritMerkha,
+        PoetryAccent::TsinnoritMahpakh,
+        PoetryAccent::Meteg,
+    ];
     #[inline]
     /// Indicates a level of importancy
     pub fn relative_strength(self) -> u8 {
@@ -373,6 +488,12 @@ pub enum PseudoAccent {
 impl PseudoAccent {
     /// Total count of all pseudo accents
     pub const COUNT: usize = 3;
+    /// All pseudo accents, in the order of the accent table
+    pub const ALL: [PseudoAccent; Self::COUNT] = [
+        PseudoAccent::SophPasuq,
+        PseudoAccent::Maqqeph,
+        PseudoAccent::Paseq,
+    ];
     #[inline]
     /// Indicates a level of importancy
     pub fn relative_strength(self) -> u8 {
@@ -381,6 +502,7 @@ impl PseudoAccent {
 }
 /// Contains (non)technical details of a Hebrew Accent
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct AccentInfo {
     /// Primary identifiers
     pub english_name: &'static str,
@@ -396,14 +518,55 @@ pub struct AccentInfo {
     pub category: Option<AccentCategory>,
     /// Indicates if the accent is on the stressed syllable
     pub word_stress: Option<WordStress>,
+    /// The allowed conjunctive accents (servi) before a disjunctive accent
+    pub servi: Option<Servi>,
     /// Unicode code‑point data.
     pub code_points: CodePoints,
     /// Free‑form comment (may be omitted)
     pub comment: Option<&'static str>,
 }
 
+/// The allowed sequences of conjunctive accents (servi) directly preceding a
+/// disjunctive accent, in reading order.
+///
+/// Every alternative is listed explicitly, e.g. `[[Munach], [Munach, Azla]]`
+/// allows a single Munach and Munach followed by Azla, but not a single Azla.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Servi {
+    /// Allowed sequences in the "Twenty-One Books" (Prose)
+    pub prose: &'static [&'static [ProseAccent]],
+    /// Allowed sequences in the "Three Books" (Poetry)
+    pub poetry: &'static [&'static [PoetryAccent]],
+}
+
+impl Servi {
+    /// Returns true if the sequence of servi is allowed
+    pub fn allows(&self, servi: &[HebrewAccent]) -> bool {
+        servi.is_empty()
+            || self
+                .prose
+                .iter()
+                .any(|alternative| equals(alternative, servi, HebrewAccent::Prose))
+            || self
+                .poetry
+                .iter()
+                .any(|alternative| equals(alternative, servi, HebrewAccent::Poetry))
+    }
+}
+
+/// Returns true if the alternative consists of exactly the servi
+fn equals<A: Copy>(alternative: &[A], servi: &[HebrewAccent], into: fn(A) -> HebrewAccent) -> bool {
+    alternative.len() == servi.len()
+        && alternative
+            .iter()
+            .zip(servi)
+            .all(|(allowed, found)| into(*allowed) == *found)
+}
+
 /// Optional alternate representations for an accent.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Alternates {
     /// Transliterated English name for the hebrew name
     pub english_name: &'static str,
@@ -414,6 +577,7 @@ pub struct Alternates {
 }
 /// Lists one or two UTF-8 code-point(s) from which the accent is constructed
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct CodePoints {
     /// Primary UTF-8 code point
     pub primary: &'static Utf8CodePointInfo,
@@ -423,6 +587,7 @@ pub struct CodePoints {
 
 /// Details on a specific UTF-8 Unicode code-point
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Utf8CodePointInfo {
     /// UTF-8 code-point, e.g. U+0591
     pub code_point: &'static str,
@@ -440,6 +605,7 @@ pub struct Utf8CodePointInfo {
 /// Names according one of four Hebrew Traditions
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 #[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Tradition {
     /// Naming of the accent according Ashkenazi tradition
     Ashkenazi {
@@ -474,6 +640,7 @@ pub enum Tradition {
 /// Hebrew Accent category (either Conjunctive or Disjunctive)
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
 #[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AccentCategory {
     /// accents that connect words
     Conjunctive,
@@ -485,6 +652,7 @@ pub enum AccentCategory {
 /// Hebrew Accent types (Primary, secondary, None)
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
 #[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AccentType {
     #[default]
     /// Indicates that the Accent is primary
@@ -498,6 +666,7 @@ pub enum AccentType {
 /// Accent position, indicating the location of the accent in relation to the consonant
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
 #[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CodePointPosition {
     /// UTF-8 code point is located above the consonant
     Above,
@@ -513,6 +682,7 @@ pub enum CodePointPosition {
 
 /// WordStress, indicating the location of the accent in relation to the consonant
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WordStress {
     #[default]
     /// The accent is located above the stressed syllable
@@ -0,0 +1,96 @@
+//! Mapping between the UTF-8 code points and the Hebrew Accents
+//!
+//! One code point can be (part of) several accents, e.g. U+0596 is Tiphcha or
+//! Mayela in prose and Tarcha in poetry. The tables are derived from the
+//! `CodePoints` of the accent data.
+
+// Standard library
+use std::collections::HashMap;
+
+// External crates
+use once_cell::sync::Lazy;
+
+// Crate‑internal (local modules)
+use crate::{Accent, Context, HebrewAccent, PoetryAccent, ProseAccent, PseudoAccent};
+
+/// Candidate accents per code point, prose and pseudo accents
+static PROSE_CHAR_TABLE: Lazy<HashMap<char, Vec<HebrewAccent>>> = Lazy::new(|| {
+    char_table(
+        ProseAccent::ALL
+            .map(HebrewAccent::from)
+            .into_iter()
+            .chain(PseudoAccent::ALL.map(HebrewAccent::from)),
+    )
+});
+
+/// Candidate accents per code point, poetry and pseudo accents
+static POETRY_CHAR_TABLE: Lazy<HashMap<char, Vec<HebrewAccent>>> = Lazy::new(|| {
+    char_table(
+        PoetryAccent::ALL
+            .map(HebrewAccent::from)
+            .into_iter()
+            .chain(PseudoAccent::ALL.map(HebrewAccent::from)),
+    )
+});
+
+/// Returns the accents the code point is (part of), taking into account the context
+///
+/// The accents are in the order of the accent tables, the pseudo accents last.
+/// Returns an empty slice if the code point is not used by any accent.
+///
+/// # Example
+/// ```
+/// use hebrew_accents::{accents_for_char, Context, PoetryAccent, ProseAccent};
+///
+/// assert_eq!(
+///     accents_for_char('\u{0596}', Context::Prosaic),
+///     [ProseAccent::Tiphcha.into(), ProseAccent::Mayela.into()]
+/// );
+/// assert_eq!(
+///     accents_for_char('\u{0596}', Context::Poetic),
+///     [PoetryAccent::Tarcha.into()]
+/// );
+/// assert!(accents_for_char('א', Context::Prosaic).is_empty());
+/// ```
+pub fn accents_for_char(c: char, ctx: Context) -> &'static [HebrewAccent] {
+    let table = match ctx {
+        Context::Poetic => &*POETRY_CHAR_TABLE,
+        Context::Prosaic => &*PROSE_CHAR_TABLE,
+    };
+    table.get(&c).map_or(&[], |accents| accents.as_slice())
+}
+
+/// Returns the code points of the accent, the primary code point first
+///
+/// # Example
+/// ```
+/// use hebrew_accents::{chars_for_accent, PoetryAccent};
+///
+/// assert_eq!(
+///     chars_for_accent(PoetryAccent::OlehWeYored.into()),
+///     ['\u{05AB}', '\u{05A5}']
+/// );
+/// ```
+pub fn chars_for_accent(accent: HebrewAccent) -> Vec<char> {
+    let code_points = accent.details().code_points;
+    std::iter::once(code_points.primary)
+        .chain(code_points.secondary)
+        .filter_map(|info| info.symbol.chars().next())
+        .collect()
+}
+
+/* helper functions */
+
+/// Builds the table of candidate accents per code point
+fn char_table(accents: impl Iterator<Item = HebrewAccent>) -> HashMap<char, Vec<HebrewAccent>> {
+    let mut table: HashMap<char, Vec<HebrewAccent>> = HashMap::new();
+    for accent in accents {
+        for c in chars_for_accent(accent) {
+            let candidates = table.entry(c).or_default();
+            if !candidates.contains(&accent) {
+                candidates.push(accent);
+            }
+        }
+    }
+    table
+}
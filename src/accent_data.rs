@@ -9,7 +9,7 @@ use once_cell::sync::Lazy;
 // Crate‑internal (local modules)
 use crate::accent::{
     AccentCategory, AccentInfo, AccentType, Alternates, CodePoints, PoetryAccent, ProseAccent,
-    WordStress,
+    Servi, WordStress,
 };
 use crate::{accent_codepoints::*, PseudoAccent};
 
@@ -123,6 +123,10 @@ pub const SILLUQ_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Disjunctive),
     word_stress: Some(WordStress::ImPositive), // TODO check/update all wordstress info
+    servi: Some(Servi {
+        prose: &[&[ProseAccent::Merkha]],
+        poetry: &[&[PoetryAccent::Munach], &[PoetryAccent::Merkha]],
+    }),
     code_points: CodePoints {
         primary: &CP_SILLUQ,
         secondary: None,
@@ -139,6 +143,17 @@ pub const ATNACH_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Disjunctive),
     word_stress: Some(WordStress::ImPositive),
+    servi: Some(Servi {
+        prose: &[
+            &[ProseAccent::Munach],
+            &[ProseAccent::Munach, ProseAccent::Munach],
+        ],
+        poetry: &[
+            &[PoetryAccent::Munach],
+            &[PoetryAccent::Merkha],
+            &[PoetryAccent::Illuy],
+        ],
+    }),
     code_points: CodePoints {
         primary: &CP_ETNAHTA,
         secondary: None,
@@ -153,6 +168,13 @@ pub const SEGOLTA_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Disjunctive),
     word_stress: Some(WordStress::PostPositive),
+    servi: Some(Servi {
+        prose: &[
+            &[ProseAccent::Munach],
+            &[ProseAccent::Munach, ProseAccent::Munach],
+        ],
+        poetry: &[],
+    }),
     code_points: CodePoints {
         primary: &CP_SEGOL,
         secondary: None,
@@ -167,6 +189,10 @@ pub const SHALSHELET_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Disjunctive),
     word_stress: Some(WordStress::ImPositive),
+    servi: Some(Servi {
+        prose: &[],
+        poetry: &[],
+    }),
     code_points: CodePoints {
         primary: &CP_SHALSHELET,
         secondary: Some(&CP_PASEQ),
@@ -185,6 +211,13 @@ pub const ZAQEF_QATON_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Disjunctive),
     word_stress: Some(WordStress::ImPositive),
+    servi: Some(Servi {
+        prose: &[
+            &[ProseAccent::Munach],
+            &[ProseAccent::Munach, ProseAccent::Munach],
+        ],
+        poetry: &[],
+    }),
     code_points: CodePoints {
         primary: &CP_ZAQEF_QATAN,
         secondary: None,
@@ -199,6 +232,10 @@ pub const ZAQEPH_GADOL_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Disjunctive),
     word_stress: Some(WordStress::ImPositive),
+    servi: Some(Servi {
+        prose: &[],
+        poetry: &[],
+    }),
     code_points: CodePoints {
         primary: &CP_ZAQEF_GADOL,
         secondary: None,
@@ -213,6 +250,14 @@ pub const REVIA_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Disjunctive),
     word_stress: Some(WordStress::ImPositive),
+    servi: Some(Servi {
+        prose: &[
+            &[ProseAccent::Munach],
+            &[ProseAccent::Darga, ProseAccent::Munach],
+            &[ProseAccent::Munach, ProseAccent::Darga, ProseAccent::Munach],
+        ],
+        poetry: &[],
+    }),
     code_points: CodePoints {
         primary: &CP_REVIA,
         secondary: None,
@@ -227,6 +272,13 @@ pub const TIPHCHA_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Disjunctive),
     word_stress: Some(WordStress::ImPositive),
+    servi: Some(Servi {
+        prose: &[
+            &[ProseAccent::Merkha],
+            &[ProseAccent::Darga, ProseAccent::Merkha],
+        ],
+        poetry: &[],
+    }),
     code_points: CodePoints {
         primary: &CP_TIPEHA,
         secondary: None,
@@ -241,6 +293,13 @@ pub const ZARQA_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Disjunctive),
     word_stress: Some(WordStress::PostPositive),
+    servi: Some(Servi {
+        prose: &[
+            &[ProseAccent::Munach],
+            &[ProseAccent::Azla, ProseAccent::Munach],
+        ],
+        poetry: &[],
+    }),
     code_points: CodePoints {
         primary: &CP_ZINOR,
         secondary: None,
@@ -255,6 +314,16 @@ pub const PASHTA_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Disjunctive),
     word_stress: Some(WordStress::PostPositive),
+    servi: Some(Servi {
+        prose: &[
+            &[ProseAccent::Mahpakh],
+            &[ProseAccent::Azla, ProseAccent::Mahpakh],
+            &[ProseAccent::Munach, ProseAccent::Mahpakh],
+            &[ProseAccent::Munach, ProseAccent::Azla, ProseAccent::Mahpakh],
+            &[ProseAccent::Merkha],
+        ],
+        poetry: &[],
+    }),
     code_points: CodePoints {
         primary: &CP_PASHTA,
         secondary: None,
@@ -269,6 +338,13 @@ pub const YETIV_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Disjunctive),
     word_stress: Some(WordStress::PrePositive),
+    servi: Some(Servi {
+        prose: &[
+            &[ProseAccent::Munach],
+            &[ProseAccent::Darga, ProseAccent::Munach],
+        ],
+        poetry: &[],
+    }),
     code_points: CodePoints {
         primary: &CP_YETIV,
         secondary: None,
@@ -283,6 +359,16 @@ pub const TEVIR_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Disjunctive),
     word_stress: Some(WordStress::ImPositive),
+    servi: Some(Servi {
+        prose: &[
+            &[ProseAccent::Darga],
+            &[ProseAccent::Azla, ProseAccent::Darga],
+            &[ProseAccent::Munach, ProseAccent::Darga],
+            &[ProseAccent::Merkha],
+            &[ProseAccent::MerkhaKephulah],
+        ],
+        poetry: &[],
+    }),
     code_points: CodePoints {
         primary: &CP_TEVIR,
         secondary: None,
@@ -301,6 +387,15 @@ pub const GERESH_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Disjunctive),
     word_stress: Some(WordStress::ImPositive),
+    servi: Some(Servi {
+        prose: &[
+            &[ProseAccent::Azla],
+            &[ProseAccent::Munach],
+            &[ProseAccent::Munach, ProseAccent::Azla],
+            &[ProseAccent::TelishaQetannah, ProseAccent::Azla],
+        ],
+        poetry: &[],
+    }),
     code_points: CodePoints {
         primary: &CP_GERESH,
         secondary: None,
@@ -315,6 +410,14 @@ pub const GERSHAYIM_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Disjunctive),
     word_stress: Some(WordStress::ImPositive),
+    servi: Some(Servi {
+        prose: &[
+            &[ProseAccent::Munach],
+            &[ProseAccent::Azla],
+            &[ProseAccent::Munach, ProseAccent::Azla],
+        ],
+        poetry: &[],
+    }),
     code_points: CodePoints {
         primary: &CP_GERSHAYIM,
         secondary: None,
@@ -333,6 +436,24 @@ pub const PAZER_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Disjunctive),
     word_stress: Some(WordStress::ImPositive),
+    servi: Some(Servi {
+        prose: &[
+            &[ProseAccent::Munach],
+            &[ProseAccent::Munach, ProseAccent::Munach],
+            &[
+                ProseAccent::Munach,
+                ProseAccent::Munach,
+                ProseAccent::Munach,
+            ],
+            &[
+                ProseAccent::Munach,
+                ProseAccent::Munach,
+                ProseAccent::Munach,
+                ProseAccent::Munach,
+            ],
+        ],
+        poetry: &[&[PoetryAccent::Galgal], &[PoetryAccent::Munach]],
+    }),
     code_points: CodePoints {
         primary: &CP_PAZER,
         secondary: None,
@@ -351,6 +472,24 @@ pub const PAZER_GADOL_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Disjunctive),
     word_stress: Some(WordStress::ImPositive),
+    servi: Some(Servi {
+        prose: &[
+            &[ProseAccent::Galgal],
+            &[ProseAccent::Munach, ProseAccent::Galgal],
+            &[
+                ProseAccent::Munach,
+                ProseAccent::Munach,
+                ProseAccent::Galgal,
+            ],
+            &[
+                ProseAccent::Munach,
+                ProseAccent::Munach,
+                ProseAccent::Munach,
+                ProseAccent::Galgal,
+            ],
+        ],
+        poetry: &[],
+    }),
     code_points: CodePoints {
         primary: &CP_QARNEY_PARA,
         secondary: None,
@@ -365,6 +504,24 @@ pub const TELISHA_GEDOLAH_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Disjunctive),
     word_stress: Some(WordStress::PrePositive),
+    servi: Some(Servi {
+        prose: &[
+            &[ProseAccent::Munach],
+            &[ProseAccent::Munach, ProseAccent::Munach],
+            &[
+                ProseAccent::Munach,
+                ProseAccent::Munach,
+                ProseAccent::Munach,
+            ],
+            &[
+                ProseAccent::Munach,
+                ProseAccent::Munach,
+                ProseAccent::Munach,
+                ProseAccent::Munach,
+            ],
+        ],
+        poetry: &[],
+    }),
     code_points: CodePoints {
         primary: &CP_TELISHA_GEDOLA,
         secondary: None,
@@ -383,6 +540,15 @@ pub const LEGARMEH_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Disjunctive),
     word_stress: Some(WordStress::ImPositive),
+    servi: Some(Servi {
+        prose: &[
+            &[ProseAccent::Merkha],
+            &[ProseAccent::Azla],
+            &[ProseAccent::Munach],
+            &[ProseAccent::Munach, ProseAccent::Munach],
+        ],
+        poetry: &[],
+    }),
     code_points: CodePoints {
         primary: &CP_MUNAH,
         secondary: Some(&CP_PASEQ),
@@ -398,6 +564,7 @@ pub const MUNACH_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Conjunctive),
     word_stress: Some(WordStress::ImPositive),
+    servi: None,
     code_points: CodePoints {
         primary: &CP_MUNAH,
         secondary: None,
@@ -416,6 +583,7 @@ pub const MAHPAKH_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Conjunctive),
     word_stress: Some(WordStress::ImPositive),
+    servi: None,
     code_points: CodePoints {
         primary: &CP_MAHAPAKH,
         secondary: None,
@@ -430,6 +598,7 @@ pub const MERKHA_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Conjunctive),
     word_stress: Some(WordStress::ImPositive),
+    servi: None,
     code_points: CodePoints {
         primary: &CP_MERKHA,
         secondary: None,
@@ -444,6 +613,7 @@ pub const MERKHA_KEFULAH_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Conjunctive),
     word_stress: Some(WordStress::ImPositive),
+    servi: None,
     code_points: CodePoints {
         primary: &CP_MERKHA_KEFULA,
         secondary: None,
@@ -458,6 +628,7 @@ pub const DARGA_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Conjunctive),
     word_stress: Some(WordStress::ImPositive),
+    servi: None,
     code_points: CodePoints {
         primary: &CP_DARGA,
         secondary: None,
@@ -477,6 +648,7 @@ pub const AZLA_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Conjunctive),
     word_stress: Some(WordStress::ImPositive),
+    servi: None,
     code_points: CodePoints {
         primary: &CP_QADMA,
         secondary: None,
@@ -491,6 +663,7 @@ pub const TELISHA_QETANNAH_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Conjunctive),
     word_stress: Some(WordStress::PostPositive),
+    servi: None,
     code_points: CodePoints {
         primary: &CP_TELISHA_QETANA,
         secondary: None,
@@ -509,6 +682,7 @@ pub const GALGAL_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Conjunctive),
     word_stress: Some(WordStress::ImPositive),
+    servi: None,
     code_points: CodePoints {
         primary: &CP_YERAH_BEN_YOMO,
         secondary: None,
@@ -527,6 +701,7 @@ pub const MAYELA_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Secondary),
     category: Some(AccentCategory::Conjunctive),
     word_stress: None,
+    servi: None,
     code_points: CodePoints {
         primary: &CP_TIPEHA,
         secondary: None,
@@ -541,6 +716,7 @@ pub const METEG_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Secondary),
     category: Some(AccentCategory::Conjunctive),
     word_stress: None,
+    servi: None,
     code_points: CodePoints {
         primary: &CP_METEG,
         secondary: None,
@@ -555,6 +731,7 @@ pub const SOPH_PASUQ_INFO: AccentInfo = AccentInfo {
     accent_type: None,
     category: None,
     word_stress: None,
+    servi: None,
     code_points: CodePoints {
         primary: &CP_SOPH_PASUQ,
         secondary: None,
@@ -569,6 +746,7 @@ pub const MAQQEPH_INFO: AccentInfo = AccentInfo {
     accent_type: None,
     category: None,
     word_stress: None,
+    servi: None,
     code_points: CodePoints {
         primary: &CP_MAQAF,
         secondary: None,
@@ -583,6 +761,7 @@ pub const PASEQ_INFO: AccentInfo = AccentInfo {
     accent_type: None,
     category: None,
     word_stress: None,
+    servi: None,
     code_points: CodePoints {
         primary: &CP_PASEQ,
         secondary: None,
@@ -612,6 +791,10 @@ pub const OLEH_WE_YORED_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Disjunctive),
     word_stress: Some(WordStress::ImPositive),
+    servi: Some(Servi {
+        prose: &[],
+        poetry: &[&[PoetryAccent::Galgal], &[PoetryAccent::Mehuppakh], &[PoetryAccent::Merkha]],
+    }),
 code_points: CodePoints {
         primary: &CP_OLE,
         secondary: Some(&CP_MERKHA),
@@ -629,6 +812,10 @@ pub const REVIA_GADOL_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Disjunctive),
     word_stress: Some(WordStress::ImPositive),
+    servi: Some(Servi {
+        prose: &[],
+        poetry: &[&[PoetryAccent::Merkha], &[PoetryAccent::Munach]],
+    }),
     code_points: CodePoints {
         primary: &CP_REVIA,
         secondary: None,
@@ -643,6 +830,14 @@ pub const REVIA_MUGRASH_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Disjunctive),
     word_stress: Some(WordStress::ImPositive),
+    servi: Some(Servi {
+        prose: &[],
+        poetry: &[
+            &[PoetryAccent::Merkha],
+            &[PoetryAccent::Munach],
+            &[PoetryAccent::Illuy],
+        ],
+    }),
     code_points: CodePoints {
         primary: &CP_GERESH,
         secondary: Some(&CP_REVIA),
@@ -657,6 +852,10 @@ pub const SHALSHELET_GADOL_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Disjunctive),
     word_stress: Some(WordStress::ImPositive),
+    servi: Some(Servi {
+        prose: &[],
+        poetry: &[],
+    }),
     code_points: CodePoints {
         primary: &CP_SHALSHELET,
         secondary: Some(&CP_PASEQ),
@@ -675,6 +874,10 @@ pub const TSINNOR_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Disjunctive),
     word_stress: Some(WordStress::PostPositive),
+    servi: Some(Servi {
+        prose: &[],
+        poetry: &[&[PoetryAccent::Merkha], &[PoetryAccent::Munach]],
+    }),
     code_points: CodePoints {
         primary: &CP_ZINOR,
         secondary: None,
@@ -689,6 +892,10 @@ pub const REVIA_QATON_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Disjunctive),
     word_stress: Some(WordStress::ImPositive),
+    servi: Some(Servi {
+        prose: &[],
+        poetry: &[&[PoetryAccent::Merkha], &[PoetryAccent::Munach]],
+    }),
     code_points: CodePoints {
         primary: &CP_REVIA,
         secondary: None,
@@ -707,6 +914,10 @@ pub const DECHI_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Disjunctive),
     word_stress: Some(WordStress::PrePositive),
+    servi: Some(Servi {
+        prose: &[],
+        poetry: &[&[PoetryAccent::Munach]],
+    }),
     code_points: CodePoints {
         primary: &CP_DEHI,
         secondary: None,
@@ -724,6 +935,10 @@ pub const MEHUPPAKH_LEGARMEH_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Disjunctive),
     word_stress: Some(WordStress::ImPositive),
+    servi: Some(Servi {
+        prose: &[],
+        poetry: &[],
+    }),
     code_points: CodePoints {
         primary: &CP_MAHAPAKH,
         secondary: Some(&CP_PASEQ),
@@ -738,6 +953,10 @@ pub const AZLA_LEGARMEH_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Disjunctive),
     word_stress: Some(WordStress::ImPositive),
+    servi: Some(Servi {
+        prose: &[],
+        poetry: &[&[PoetryAccent::Merkha]],
+    }),
     code_points: CodePoints {
         primary: &CP_QADMA,
         secondary: Some(&CP_PASEQ),
@@ -757,6 +976,7 @@ pub const ILLUY_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Conjunctive),
     word_stress: Some(WordStress::ImPositive),
+    servi: None,
     code_points: CodePoints {
         primary: &CP_ILUY,
         secondary: None,
@@ -771,6 +991,7 @@ pub const TARCHA_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Conjunctive),
     word_stress: Some(WordStress::ImPositive),
+    servi: None,
     code_points: CodePoints {
         primary: &CP_TIPEHA,
         secondary: None,
@@ -792,6 +1013,7 @@ pub const MEHUPPAKH_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Conjunctive),
     word_stress: Some(WordStress::ImPositive),
+    servi: None,
     code_points: CodePoints {
         primary: &CP_MAHAPAKH,
         secondary: None,
@@ -809,6 +1031,7 @@ pub const SHALSHELET_QETANNAH_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Conjunctive),
     word_stress: Some(WordStress::ImPositive),
+    servi: None,
     code_points: CodePoints {
         primary: &CP_SHALSHELET,
         secondary: None,
@@ -823,6 +1046,7 @@ pub const TSINNORIT_MERKHA_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Conjunctive),
     word_stress: Some(WordStress::ImPositive),
+    servi: None,
     code_points: CodePoints {
         primary: &CP_ZARQA,
         secondary: Some(&CP_MERKHA),
@@ -837,6 +1061,7 @@ pub const TSINNORIT_MAHPAKH_INFO: AccentInfo = AccentInfo {
     accent_type: Some(AccentType::Primary),
     category: Some(AccentCategory::Conjunctive),
     word_stress: Some(WordStress::ImPositive),
+    servi: None,
     code_points: CodePoints {
         primary: &CP_ZARQA,
         secondary: Some(&CP_MAHAPAKH),
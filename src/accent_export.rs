@@ -0,0 +1,380 @@
+//! Export of the accent tables as JSON, CSV or Markdown
+//!
+//! Every row holds the rank (relative strength), the names, the alternates,
+//! type, category, word stress, the code points (with hex values, positions
+//! and the names in the Jewish traditions) and the comment of an accent.
+
+// Standard library
+use std::io;
+
+// External crates
+
+// Crate‑internal (local modules)
+use crate::{
+    Accent, AccentInfo, HebrewAccent, PoetryAccent, ProseAccent, PseudoAccent, Utf8CodePointInfo,
+};
+#[cfg(feature = "serde")]
+use crate::{AccentCategory, AccentType, Alternates, CodePointPosition, TraditionKind, WordStress};
+
+/// One of the static accent tables
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum AccentTable {
+    /// The accents of the "Twenty-One Books"
+    Prose,
+    /// The accents of the "Three Books"
+    Poetry,
+    /// The marks related to the accents
+    Pseudo,
+}
+
+/// Output format of an accent table
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum TableFormat {
+    /// An array of objects, one per accent
+    Json,
+    /// Comma separated values with a header line (RFC 4180 quoting)
+    Csv,
+    /// A Markdown (GitHub flavored) table
+    Markdown,
+}
+
+/// The columns of the CSV and Markdown formats
+const COLUMNS: [&str; 14] = [
+    "rank",
+    "english_name",
+    "hebrew_name",
+    "meaning",
+    "alternate_english_name",
+    "alternate_hebrew_name",
+    "accent_type",
+    "category",
+    "word_stress",
+    "code_points",
+    "hex_values",
+    "positions",
+    "traditions",
+    "comment",
+];
+
+impl AccentTable {
+    /// Returns the accents of the table, in table order
+    pub fn accents(self) -> Vec<HebrewAccent> {
+        match self {
+            AccentTable::Prose => ProseAccent::ALL.map(HebrewAccent::from).to_vec(),
+            AccentTable::Poetry => PoetryAccent::ALL.map(HebrewAccent::from).to_vec(),
+            AccentTable::Pseudo => PseudoAccent::ALL.map(HebrewAccent::from).to_vec(),
+        }
+    }
+}
+
+/// Writes the accent table in the format
+///
+/// # Errors
+/// Returns the error of the writer.
+///
+/// # Example
+/// ```
+/// use hebrew_accents::{write_accent_table, AccentTable, TableFormat};
+///
+/// let mut csv = Vec::new();
+/// write_accent_table(&mut csv, AccentTable::Prose, TableFormat::Csv).unwrap();
+/// let csv = String::from_utf8(csv).unwrap();
+/// assert!(csv.starts_with("rank,english_name,hebrew_name,meaning,"));
+/// assert!(csv.lines().nth(2).unwrap().starts_with("2,Atnach,"));
+/// ```
+pub fn write_accent_table<W: io::Write>(
+    writer: &mut W,
+    table: AccentTable,
+    format: TableFormat,
+) -> io::Result<()> {
+    let accents = table.accents();
+    match format {
+        TableFormat::Json => write_json(writer, &accents),
+        TableFormat::Csv => write_csv(writer, &accents),
+        TableFormat::Markdown => write_markdown(writer, &accents),
+    }
+}
+
+/* helper functions */
+
+/// The code points of the accent, the primary code point first
+fn code_points(info: &AccentInfo) -> Vec<&'static Utf8CodePointInfo> {
+    std::iter::once(info.code_points.primary)
+        .chain(info.code_points.secondary)
+        .collect()
+}
+
+/// Debug name of an optional enum value, empty if None
+fn name_of<T: std::fmt::Debug>(value: Option<T>) -> String {
+    value.map_or(String::new(), |value| format!("{:?}", value))
+}
+
+/// The values of the columns of the accent
+fn row(accent: HebrewAccent) -> [String; 14] {
+    let info = accent.details();
+    let cps = code_points(info);
+    let join = |f: fn(&Utf8CodePointInfo) -> String| {
+        cps.iter().map(|cp| f(cp)).collect::<Vec<_>>().join(" + ")
+    };
+    let traditions = cps
+        .iter()
+        .flat_map(|cp| cp.traditions.iter())
+        .map(|tradition| {
+            format!(
+                "{:?}: {} ({})",
+                tradition.kind(),
+                tradition.english_name(),
+                tradition.hebrew_name()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("; ");
+    [
+        accent.relative_strength().to_string(),
+        info.english_name.to_string(),
+        info.hebrew_name.to_string(),
+        info.meaning.to_string(),
+        info.alternates
+            .map_or(String::new(), |alt| alt.english_name.to_string()),
+        info.alternates
+            .map_or(String::new(), |alt| alt.hebrew_name.to_string()),
+        name_of(info.accent_type),
+        name_of(info.category),
+        name_of(info.word_stress),
+        join(|cp| cp.code_point.to_string()),
+        join(|cp| cp.hex_value.to_string()),
+        join(|cp| format!("{:?}", cp.position)),
+        traditions,
+        info.comment.unwrap_or_default().to_string(),
+    ]
+}
+
+fn write_csv<W: io::Write>(writer: &mut W, accents: &[HebrewAccent]) -> io::Result<()> {
+    writeln!(writer, "{}", COLUMNS.join(","))?;
+    for accent in accents {
+        let fields: Vec<String> = row(*accent).iter().map(|f| csv_field(f)).collect();
+        writeln!(writer, "{}", fields.join(","))?;
+    }
+    Ok(())
+}
+
+fn write_markdown<W: io::Write>(writer: &mut W, accents: &[HebrewAccent]) -> io::Result<()> {
+    writeln!(writer, "| {} |", COLUMNS.join(" | "))?;
+    writeln!(writer, "|{}", "---|".repeat(COLUMNS.len()))?;
+    for accent in accents {
+        let cells: Vec<String> = row(*accent).iter().map(|f| markdown_cell(f)).collect();
+        writeln!(writer, "| {} |", cells.join(" | "))?;
+    }
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+fn write_json<W: io::Write>(writer: &mut W, accents: &[HebrewAccent]) -> io::Result<()> {
+    let accents: Vec<JsonAccent> = accents.iter().map(|accent| json_accent(*accent)).collect();
+    serde_json::to_writer_pretty(&mut *writer, &accents)?;
+    writeln!(writer)
+}
+
+#[cfg(not(feature = "serde"))]
+fn write_json<W: io::Write>(writer: &mut W, accents: &[HebrewAccent]) -> io::Result<()> {
+    writeln!(writer, "[")?;
+    for (idx, accent) in accents.iter().enumerate() {
+        let info = accent.details();
+        writeln!(writer, "  {{")?;
+        writeln!(writer, "    \"rank\": {},", accent.relative_strength())?;
+        writeln!(
+            writer,
+            "    \"english_name\": {},",
+            json_str(info.english_name)
+        )?;
+        writeln!(
+            writer,
+            "    \"hebrew_name\": {},",
+            json_str(info.hebrew_name)
+        )?;
+        writeln!(writer, "    \"meaning\": {},", json_str(info.meaning))?;
+        match info.alternates {
+            Some(alt) => writeln!(
+                writer,
+                "    \"alternates\": {{\"english_name\": {}, \"hebrew_name\": {}, \"meaning\": {}}},",
+                json_str(alt.english_name),
+                json_str(alt.hebrew_name),
+                json_str(alt.meaning)
+            )?,
+            None => writeln!(writer, "    \"alternates\": null,")?,
+        }
+        writeln!(
+            writer,
+            "    \"accent_type\": {},",
+            json_opt(info.accent_type)
+        )?;
+        writeln!(writer, "    \"category\": {},", json_opt(info.category))?;
+        writeln!(
+            writer,
+            "    \"word_stress\": {},",
+            json_opt(info.word_stress)
+        )?;
+        writeln!(writer, "    \"code_points\": [")?;
+        let cps = code_points(info);
+        for (cp_idx, cp) in cps.iter().enumerate() {
+            let traditions: Vec<String> = cp
+                .traditions
+                .iter()
+                .map(|tradition| {
+                    format!(
+                        "{{\"tradition\": {}, \"english_name\": {}, \"hebrew_name\": {}}}",
+                        json_str(&format!("{:?}", tradition.kind())),
+                        json_str(tradition.english_name()),
+                        json_str(tradition.hebrew_name())
+                    )
+                })
+                .collect();
+            writeln!(
+                writer,
+                "      {{\"code_point\": {}, \"hex_value\": {}, \"name\": {}, \"position\": {}, \"traditions\": [{}]}}{}",
+                json_str(cp.code_point),
+                json_str(cp.hex_value),
+                json_str(cp.name),
+                json_str(&format!("{:?}", cp.position)),
+                traditions.join(", "),
+                if cp_idx + 1 < cps.len() { "," } else { "" }
+            )?;
+        }
+        writeln!(writer, "    ],")?;
+        match info.comment {
+            Some(comment) => writeln!(writer, "    \"comment\": {}", json_str(comment))?,
+            None => writeln!(writer, "    \"comment\": null")?,
+        }
+        let separator = if idx + 1 < accents.len() { "," } else { "" };
+        writeln!(writer, "  }}{}", separator)?;
+    }
+    writeln!(writer, "]")
+}
+
+/// A JSON string literal
+#[cfg(not(feature = "serde"))]
+pub(crate) fn json_str(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if u32::from(c) < 0x20 => quoted.push_str(&format!("\\u{:04x}", u32::from(c))),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// A JSON string literal of the Debug name, or null
+#[cfg(not(feature = "serde"))]
+fn json_opt<T: std::fmt::Debug>(value: Option<T>) -> String {
+    match value {
+        Some(value) => json_str(&format!("{:?}", value)),
+        None => "null".to_string(),
+    }
+}
+
+/// An accent of the JSON format
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+struct JsonAccent {
+    rank: u8,
+    english_name: &'static str,
+    hebrew_name: &'static str,
+    meaning: &'static str,
+    alternates: Option<Alternates>,
+    accent_type: Option<AccentType>,
+    category: Option<AccentCategory>,
+    word_stress: Option<WordStress>,
+    code_points: Vec<JsonCodePoint>,
+    comment: Option<&'static str>,
+}
+
+/// A code point of an accent of the JSON format
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+struct JsonCodePoint {
+    code_point: &'static str,
+    hex_value: &'static str,
+    name: &'static str,
+    position: CodePointPosition,
+    traditions: Vec<JsonTradition>,
+}
+
+/// A name of a code point in a tradition of the JSON format
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+struct JsonTradition {
+    tradition: TraditionKind,
+    english_name: &'static str,
+    hebrew_name: &'static str,
+}
+
+#[cfg(feature = "serde")]
+fn json_accent(accent: HebrewAccent) -> JsonAccent {
+    let info = accent.details();
+    JsonAccent {
+        rank: accent.relative_strength(),
+        english_name: info.english_name,
+        hebrew_name: info.hebrew_name,
+        meaning: info.meaning,
+        alternates: info.alternates,
+        accent_type: info.accent_type,
+        category: info.category,
+        word_stress: info.word_stress,
+        code_points: code_points(info)
+            .into_iter()
+            .map(|cp| JsonCodePoint {
+                code_point: cp.code_point,
+                hex_value: cp.hex_value,
+                name: cp.name,
+                position: cp.position,
+                traditions: cp
+                    .traditions
+                    .iter()
+                    .map(|tradition| JsonTradition {
+                        tradition: tradition.kind(),
+                        english_name: tradition.english_name(),
+                        hebrew_name: tradition.hebrew_name(),
+                    })
+                    .collect(),
+            })
+            .collect(),
+        comment: info.comment,
+    }
+}
+
+/// A CSV field, quoted if needed
+pub(crate) fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+/// A Markdown table cell
+pub(crate) fn markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace(['\n', '\r'], " ")
+}
+
+#[cfg(all(test, not(feature = "serde")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_str_control_and_non_bmp() {
+        // control characters, quotes, a Hebrew accent and a non-BMP character
+        let text = "\u{0}\u{1f}\t\n\r\"\\ \u{0591} \u{1D11E}\u{1F600}";
+        let quoted = json_str(text);
+        assert!(quoted.contains("\\u0000\\u001f\\t\\n\\r"));
+        let parsed: String = serde_json::from_str(&quoted).unwrap();
+        assert_eq!(parsed, text);
+    }
+}
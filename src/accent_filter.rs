@@ -0,0 +1,114 @@
+//! Selection of accents for display text
+//!
+
+// Standard library
+
+// External crates
+
+// Crate‑internal (local modules)
+use crate::{Accent, AccentCategory, AccentType, HebrewAccent};
+
+/// Selects the accents (and other marks) to keep in a rendered sentence,
+/// see [`SentenceContext::render_filtered`](crate::SentenceContext::render_filtered)
+///
+/// An accent is kept if it is named, or if its category or type is named.
+/// Pseudo accents (Maqqeph, Paseq and Sof Pasuq), vowels and consonant points
+/// are kept by default.
+///
+/// # Example
+/// ```
+/// use hebrew_accents::{AccentCategory, AccentFilter, ProseAccent};
+///
+/// // only the disjunctives
+/// let filter = AccentFilter::new().category(AccentCategory::Disjunctive);
+/// // only the Atnach/Silluq skeleton, without vowels
+/// let filter = AccentFilter::new()
+///     .accent(ProseAccent::Atnach.into())
+///     .accent(ProseAccent::Silluq.into())
+///     .vowels(false);
+/// assert!(filter.selects(ProseAccent::Atnach.into()));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AccentFilter {
+    /// The accents to keep
+    pub accents: Vec<HebrewAccent>,
+    /// The categories of the accents to keep
+    pub categories: Vec<AccentCategory>,
+    /// The types of the accents to keep
+    pub types: Vec<AccentType>,
+    /// Keep all pseudo accents (Maqqeph, Paseq and Sof Pasuq)
+    pub pseudo_accents: bool,
+    /// Keep the vowels (niqqud)
+    pub vowels: bool,
+    /// Keep the consonant points (Dagesh, Rafe, Shin and Sin dots)
+    pub points: bool,
+}
+
+impl Default for AccentFilter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AccentFilter {
+    /// Creates a filter without accents, keeping the pseudo accents,
+    /// the vowels and the consonant points
+    pub fn new() -> Self {
+        AccentFilter {
+            accents: Vec::new(),
+            categories: Vec::new(),
+            types: Vec::new(),
+            pseudo_accents: true,
+            vowels: true,
+            points: true,
+        }
+    }
+
+    /// Keeps the accent
+    pub fn accent(mut self, accent: HebrewAccent) -> Self {
+        self.accents.push(accent);
+        self
+    }
+
+    /// Keeps all accents of the category
+    pub fn category(mut self, category: AccentCategory) -> Self {
+        self.categories.push(category);
+        self
+    }
+
+    /// Keeps all accents of the type
+    pub fn accent_type(mut self, accent_type: AccentType) -> Self {
+        self.types.push(accent_type);
+        self
+    }
+
+    /// Keeps or drops the pseudo accents that are not named
+    pub fn pseudo_accents(mut self, keep: bool) -> Self {
+        self.pseudo_accents = keep;
+        self
+    }
+
+    /// Keeps or drops the vowels
+    pub fn vowels(mut self, keep: bool) -> Self {
+        self.vowels = keep;
+        self
+    }
+
+    /// Keeps or drops the consonant points
+    pub fn points(mut self, keep: bool) -> Self {
+        self.points = keep;
+        self
+    }
+
+    /// Returns true if the accent is kept
+    pub fn selects(&self, accent: HebrewAccent) -> bool {
+        self.accents.contains(&accent)
+            || (self.pseudo_accents && matches!(accent, HebrewAccent::Pseudo(_)))
+            || accent
+                .category()
+                .is_some_and(|category| self.categories.contains(&category))
+            || accent
+                .accent_type()
+                .is_some_and(|accent_type| self.types.contains(&accent_type))
+    }
+}
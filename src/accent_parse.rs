@@ -0,0 +1,186 @@
+//! Parsing accent names (reverse lookup of the accent data)
+//!
+//! A name is resolved in two rounds: first the BHS names and the `Alternates`
+//! (English and Hebrew), then the names of the Jewish traditions (see
+//! [`Tradition`](crate::Tradition)). The first round with a match decides,
+//! so a BHS name is never ambiguous with a tradition name of another accent.
+//!
+//! Names are compared ignoring case, spaces, punctuation, Hebrew points and
+//! accents and Latin diacritics, e.g. "Zaqeph Qaton", "zaqephqaton" and
+//! "זקף קטון" are the same name.
+
+// Standard library
+use std::str::FromStr;
+
+// External crates
+
+// Crate‑internal (local modules)
+use crate::{Accent, Context, Error, HebrewAccent, PoetryAccent, ProseAccent, PseudoAccent};
+
+impl FromStr for ProseAccent {
+    type Err = Error;
+
+    /// Parses the name of a prose accent
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_accents::ProseAccent;
+    ///
+    /// assert_eq!("Etnachta".parse::<ProseAccent>().unwrap(), ProseAccent::Atnach);
+    /// assert_eq!("אתנח".parse::<ProseAccent>().unwrap(), ProseAccent::Atnach);
+    /// assert_eq!("qadma".parse::<ProseAccent>().unwrap(), ProseAccent::Azla);
+    /// ```
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        lookup(name, &ProseAccent::ALL)
+    }
+}
+
+impl FromStr for PoetryAccent {
+    type Err = Error;
+
+    /// Parses the name of a poetry accent
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        lookup(name, &PoetryAccent::ALL)
+    }
+}
+
+impl FromStr for PseudoAccent {
+    type Err = Error;
+
+    /// Parses the name of a pseudo accent
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        lookup(name, &PseudoAccent::ALL)
+    }
+}
+
+impl FromStr for HebrewAccent {
+    type Err = Error;
+
+    /// Parses the name of any accent
+    ///
+    /// Most names are used both in prose and in poetry, use
+    /// [`HebrewAccent::parse_in`] to resolve those.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_accents::{Error, HebrewAccent, PoetryAccent, ProseAccent};
+    ///
+    /// let accent: HebrewAccent = "Oleh We Yored".parse().unwrap();
+    /// assert_eq!(accent, PoetryAccent::OlehWeYored.into());
+    /// assert!(matches!(
+    ///     "Atnach".parse::<HebrewAccent>(),
+    ///     Err(Error::AmbiguousAccent { .. })
+    /// ));
+    /// ```
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        lookup(name, &all_accents(None))
+    }
+}
+
+impl HebrewAccent {
+    /// Parses the name of an accent of the accent system of the context,
+    /// including the pseudo accents
+    ///
+    /// # Errors
+    /// Returns [`Error::UnknownAccent`] if no accent has the name and
+    /// [`Error::AmbiguousAccent`] if more than one accent has the name.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_accents::{Context, HebrewAccent, PoetryAccent};
+    ///
+    /// let accent = HebrewAccent::parse_in("Atnach", Context::Poetic).unwrap();
+    /// assert_eq!(accent, PoetryAccent::Atnach.into());
+    /// ```
+    pub fn parse_in(name: &str, ctx: Context) -> Result<HebrewAccent, Error> {
+        lookup(name, &all_accents(Some(ctx)))
+    }
+}
+
+/* helper functions */
+
+/// All accents, or the accents of the context and the pseudo accents
+fn all_accents(ctx: Option<Context>) -> Vec<HebrewAccent> {
+    let mut accents = Vec::new();
+    if ctx != Some(Context::Poetic) {
+        accents.extend(ProseAccent::ALL.map(HebrewAccent::from));
+    }
+    if ctx != Some(Context::Prosaic) {
+        accents.extend(PoetryAccent::ALL.map(HebrewAccent::from));
+    }
+    accents.extend(PseudoAccent::ALL.map(HebrewAccent::from));
+    accents
+}
+
+/// Finds the single accent with the name, BHS names before tradition names
+fn lookup<A: Accent + Into<HebrewAccent>>(name: &str, accents: &[A]) -> Result<A, Error> {
+    let key = fold(name);
+    if key.is_empty() {
+        return Err(Error::UnknownAccent(name.to_string()));
+    }
+    for names in [bhs_names::<A>, tradition_names::<A>] {
+        let candidates: Vec<A> = accents
+            .iter()
+            .copied()
+            .filter(|accent| names(*accent).into_iter().any(|n| fold(n) == key))
+            .collect();
+        match candidates.as_slice() {
+            [] => continue,
+            [accent] => return Ok(*accent),
+            _ => {
+                return Err(Error::AmbiguousAccent {
+                    name: name.to_string(),
+                    candidates: candidates.into_iter().map(Into::into).collect(),
+                })
+            }
+        }
+    }
+    Err(Error::UnknownAccent(name.to_string()))
+}
+
+/// The BHS names and the alternate names of the accent
+fn bhs_names<A: Accent>(accent: A) -> Vec<&'static str> {
+    let info = accent.details();
+    let mut names = vec![info.english_name, info.hebrew_name];
+    if let Some(alternates) = info.alternates {
+        names.extend([alternates.english_name, alternates.hebrew_name]);
+    }
+    // placeholders in the accent data
+    names.retain(|name| *name != "todo");
+    names
+}
+
+/// The names of the accent in the Jewish traditions
+fn tradition_names<A: Accent>(accent: A) -> Vec<&'static str> {
+    accent
+        .traditions()
+        .iter()
+        .flat_map(|tradition| [tradition.english_name(), tradition.hebrew_name()])
+        .collect()
+}
+
+/// Folds a name for comparison: lowercase letters and digits only, without Hebrew
+/// points and accents and without Latin diacritics
+fn fold(name: &str) -> String {
+    name.chars()
+        .filter(|c| !matches!(c, '\u{0591}'..='\u{05C7}' | '\u{0300}'..='\u{036F}'))
+        .map(strip_diacritic)
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Replaces a precomposed Latin letter by its base letter
+fn strip_diacritic(c: char) -> char {
+    match c {
+        'á' | 'à' | 'â' | 'ä' | 'ā' | 'Á' | 'À' | 'Â' | 'Ä' | 'Ā' => 'a',
+        'é' | 'è' | 'ê' | 'ë' | 'ē' | 'É' | 'È' | 'Ê' | 'Ë' | 'Ē' => 'e',
+        'í' | 'ì' | 'î' | 'ï' | 'ī' | 'Í' | 'Ì' | 'Î' | 'Ï' | 'Ī' => 'i',
+        'ó' | 'ò' | 'ô' | 'ö' | 'ō' | 'Ó' | 'Ò' | 'Ô' | 'Ö' | 'Ō' => 'o',
+        'ú' | 'ù' | 'û' | 'ü' | 'ū' | 'Ú' | 'Ù' | 'Û' | 'Ü' | 'Ū' => 'u',
+        'ḥ' | 'Ḥ' => 'h',
+        'ṭ' | 'Ṭ' => 't',
+        'ṣ' | 'Ṣ' => 's',
+        other => other,
+    }
+}
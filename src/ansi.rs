@@ -0,0 +1,118 @@
+//! Terminal output with colored accents (ANSI escape codes)
+//!
+
+// Standard library
+use std::ffi::OsStr;
+use std::io::IsTerminal;
+
+// External crates
+
+// Crate‑internal (local modules)
+use crate::sentence_ctx_parse_tree::level;
+use crate::{Accent, AccentCategory, Context, HebrewAccent, PoetryAccent, ProseAccent};
+
+/// Resets all attributes
+const RESET: &str = "\x1b[0m";
+
+/// Coloring of the words carrying an accent
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub enum AnsiScheme {
+    /// Disjunctives red, conjunctives blue, other accents gray
+    #[default]
+    Category,
+    /// Disjunctives by their level in the dichotomy (from bold red for Silluq
+    /// down to cyan), conjunctives blue, other accents gray
+    Strength,
+}
+
+/// Returns true if colored output should be written to stdout:
+/// the `NO_COLOR` environment variable is not set (or empty) and stdout is a terminal
+pub fn use_color() -> bool {
+    use_color_with(
+        std::env::var_os("NO_COLOR").as_deref(),
+        std::io::stdout().is_terminal(),
+    )
+}
+
+/// Returns true if colored output should be written, given the value of the
+/// `NO_COLOR` environment variable and whether the output is a terminal
+///
+/// # Example
+/// ```
+/// use hebrew_accents::use_color_with;
+/// use std::ffi::OsStr;
+///
+/// assert!(use_color_with(None, true));
+/// assert!(use_color_with(Some(OsStr::new("")), true));
+/// assert!(!use_color_with(Some(OsStr::new("1")), true));
+/// assert!(!use_color_with(None, false));
+/// ```
+pub fn use_color_with(no_color: Option<&OsStr>, is_terminal: bool) -> bool {
+    let no_color = no_color.is_some_and(|value| !value.is_empty());
+    !no_color && is_terminal
+}
+
+/// Returns the legend of the scheme for the accents of the context,
+/// one line per color
+///
+/// # Example
+/// ```
+/// use hebrew_accents::{ansi_legend, AnsiScheme, Context};
+///
+/// let legend = ansi_legend(AnsiScheme::Category, Context::Prosaic, false);
+/// assert!(legend.starts_with("disjunctive: Silluq, Atnach, Segolta"));
+/// ```
+pub fn ansi_legend(scheme: AnsiScheme, ctx: Context, color: bool) -> String {
+    let accents: Vec<HebrewAccent> = match ctx {
+        Context::Poetic => PoetryAccent::ALL.map(HebrewAccent::from).to_vec(),
+        Context::Prosaic => ProseAccent::ALL.map(HebrewAccent::from).to_vec(),
+    };
+    let mut groups: Vec<(String, &'static str, Vec<&'static str>)> = Vec::new();
+    for accent in accents {
+        let label = match (scheme, accent.category()) {
+            (AnsiScheme::Strength, Some(AccentCategory::Disjunctive)) => match level(accent) {
+                Some(level) => format!("level {}", level),
+                None => "disjunctive".to_string(),
+            },
+            (_, Some(AccentCategory::Disjunctive)) => "disjunctive".to_string(),
+            (_, Some(AccentCategory::Conjunctive)) => "conjunctive".to_string(),
+            (_, None) => "other".to_string(),
+        };
+        match groups.iter_mut().find(|(l, _, _)| *l == label) {
+            Some((_, _, names)) => names.push(accent.english_name()),
+            None => groups.push((label, sgr(scheme, accent), vec![accent.english_name()])),
+        }
+    }
+    let mut legend = String::new();
+    for (label, code, names) in groups {
+        let line = format!("{}: {}", label, names.join(", "));
+        legend.push_str(&paint(&line, code, color));
+        legend.push('\n');
+    }
+    legend
+}
+
+/// Returns the SGR parameters of the color of the accent
+pub(crate) fn sgr(scheme: AnsiScheme, accent: HebrewAccent) -> &'static str {
+    match accent.category() {
+        Some(AccentCategory::Disjunctive) => match (scheme, level(accent)) {
+            (AnsiScheme::Strength, Some(0)) => "1;31",
+            (AnsiScheme::Strength, Some(1)) => "31",
+            (AnsiScheme::Strength, Some(2)) => "33",
+            (AnsiScheme::Strength, Some(3)) => "32",
+            (AnsiScheme::Strength, Some(_)) => "36",
+            _ => "31",
+        },
+        Some(AccentCategory::Conjunctive) => "34",
+        None => "90",
+    }
+}
+
+/// Wraps the text in the color, or returns the text if color is off
+pub(crate) fn paint(text: &str, code: &str, color: bool) -> String {
+    if color {
+        format!("\x1b[{}m{}{}", code, text, RESET)
+    } else {
+        text.to_string()
+    }
+}
@@ -0,0 +1,347 @@
+//! Command-line interface to the `hebrew_accents` library
+//!
+//! Reads verses (one per line) from files or stdin and finds, lists or counts
+//! their accents, or prints the accent reference tables.
+
+// Standard library
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+// External crates
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+// Crate‑internal (local modules)
+use hebrew_accents::{
+    use_color, write_accent_table, Accent, AccentTable, AnsiScheme, Context, CorpusFormat,
+    CorpusReader, Error, HebrewAccent, Reference, SentenceContext, Statistics, TableFormat,
+};
+
+/// Finding, listing and counting Hebrew accents
+#[derive(Debug, Parser)]
+#[command(name = "hebrew-accents", version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Prints the verses containing an accent
+    Grep {
+        /// The name of the accent, e.g. Atnach, Etnachta or אתנח
+        accent: String,
+        /// Number of verses to print before and after a matching verse
+        #[arg(short = 'C', long, default_value_t = 0)]
+        context: usize,
+        /// When to color the words carrying the accent
+        #[arg(long, value_enum, default_value_t = ColorChoice::Auto)]
+        color: ColorChoice,
+        #[command(flatten)]
+        input: Input,
+    },
+    /// Lists every word of every verse with its accent(s)
+    Analyze {
+        #[command(flatten)]
+        input: Input,
+    },
+    /// Prints how often every accent occurs
+    Stats {
+        /// Writes all statistics (sequences, co-occurrences, positions) in the format
+        #[arg(long, value_enum)]
+        format: Option<Format>,
+        #[command(flatten)]
+        input: Input,
+    },
+    /// Prints the accent reference data
+    Tables {
+        /// The accent table
+        #[arg(long, value_enum, default_value_t = Table::Prose)]
+        table: Table,
+        /// The output format
+        #[arg(long, value_enum, default_value_t = Format::Markdown)]
+        format: Format,
+    },
+}
+
+/// The verses to read and their context
+#[derive(Debug, Args)]
+struct Input {
+    /// The verses are poetry (Psalms, Proverbs, Job)
+    #[arg(long, conflicts_with = "prosaic")]
+    poetic: bool,
+    /// The verses are prose (default, with --tsv the context follows the book)
+    #[arg(long)]
+    prosaic: bool,
+    /// The lines are tab-separated: book, chapter, verse and text
+    #[arg(long)]
+    tsv: bool,
+    /// Files with one verse per line, stdin if none (or `-`)
+    files: Vec<PathBuf>,
+}
+
+#[derive(Debug, Copy, Clone, ValueEnum)]
+enum ColorChoice {
+    Auto,
+    Always,
+    Never,
+}
+
+#[derive(Debug, Copy, Clone, ValueEnum)]
+enum Table {
+    Prose,
+    Poetry,
+    Pseudo,
+}
+
+#[derive(Debug, Copy, Clone, ValueEnum)]
+enum Format {
+    Json,
+    Csv,
+    Markdown,
+}
+
+/// A verse and where it was read
+struct Verse {
+    source: String,
+    line: usize,
+    reference: Option<Reference>,
+    sc: SentenceContext,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Grep {
+            accent,
+            context,
+            color,
+            input,
+        } => grep(&accent, context, color, &input),
+        Command::Analyze { input } => analyze(&input).map(|_| true),
+        Command::Stats { format, input } => stats(format, &input).map(|_| true),
+        Command::Tables { table, format } => tables(table, format).map(|_| true),
+    };
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        // e.g. piped into `head`
+        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("hebrew-accents: {}", err);
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Prints the verses with the accent, returns false if there are none
+fn grep(name: &str, context: usize, color: ColorChoice, input: &Input) -> io::Result<bool> {
+    // with --tsv the verses may be prose and poetry, the name is resolved in both
+    let resolve = |ctx| HebrewAccent::parse_in(name, ctx).map_err(invalid_input);
+    let (prose, poetry) = match input.context() {
+        Some(Context::Poetic) => (None, Some(resolve(Context::Poetic)?)),
+        Some(_) => (Some(resolve(Context::Prosaic)?), None),
+        None => match (resolve(Context::Prosaic), resolve(Context::Poetic)) {
+            (Err(err), Err(_)) => return Err(err),
+            (prose, poetry) => (prose.ok(), poetry.ok()),
+        },
+    };
+    let accent_in = |ctx| {
+        if ctx == Context::Poetic {
+            poetry
+        } else {
+            prose
+        }
+    };
+    let color = match color {
+        ColorChoice::Auto => use_color(),
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+    };
+    let verses = input.read()?;
+    let hits: Vec<usize> = verses
+        .iter()
+        .enumerate()
+        .filter(|(_, verse)| {
+            accent_in(verse.sc.ctx).is_some_and(|accent| {
+                verse
+                    .sc
+                    .try_contains_accent(accent)
+                    .unwrap_or_else(|err| verse.report(err, false))
+            })
+        })
+        .map(|(idx, _)| idx)
+        .collect();
+    let mut out = io::stdout().lock();
+    let mut printed_until = 0;
+    for &hit in &hits {
+        let from = hit.saturating_sub(context).max(printed_until);
+        let to = (hit + context + 1).min(verses.len());
+        if context > 0 && printed_until > 0 && from > printed_until {
+            writeln!(out, "--")?;
+        }
+        for (idx, verse) in verses.iter().enumerate().take(to).skip(from) {
+            let separator = if hits.binary_search(&idx).is_ok() {
+                ':'
+            } else {
+                '-'
+            };
+            let text = match accent_in(verse.sc.ctx) {
+                Some(accent) if separator == ':' => verse
+                    .sc
+                    .try_to_ansi_accent(accent, AnsiScheme::Category, color)
+                    .unwrap_or_else(|err| verse.report(err, verse.sc.sentence.clone())),
+                _ => verse.sc.sentence.clone(),
+            };
+            writeln!(
+                out,
+                "{}{}{}{} {}",
+                verse.source, separator, verse.line, separator, text
+            )?;
+        }
+        printed_until = to;
+    }
+    Ok(!hits.is_empty())
+}
+
+/// Lists the words of every verse with their accents
+fn analyze(input: &Input) -> io::Result<()> {
+    let mut out = io::stdout().lock();
+    for verse in input.read()? {
+        match verse.reference {
+            Some(reference) => writeln!(out, "{}:{} {}", verse.source, verse.line, reference)?,
+            None => writeln!(out, "{}:{}", verse.source, verse.line)?,
+        }
+        let words = match verse.sc.try_words() {
+            Ok(words) => words,
+            Err(err) => verse.report(err, Vec::new()),
+        };
+        for word in words {
+            let names: Vec<&str> = word
+                .accents()
+                .into_iter()
+                .map(|accent| accent.english_name())
+                .collect();
+            writeln!(out, "  {}\t{}", word.as_str(), names.join(", "))?;
+        }
+    }
+    Ok(())
+}
+
+/// Prints the number of occurrences of every accent, most frequent first,
+/// or all statistics in the format
+fn stats(format: Option<Format>, input: &Input) -> io::Result<()> {
+    let mut stats = Statistics::new();
+    for verse in input.read()? {
+        if let Err(err) = stats.try_add(&verse.sc) {
+            verse.report(err, ());
+        }
+    }
+    let mut out = io::stdout().lock();
+    if let Some(format) = format {
+        return stats.write(&mut out, table_format(format));
+    }
+    let total = stats.total();
+    writeln!(out, "{:<20} {:>8} {:>7}", "accent", "count", "%")?;
+    for (accent, count) in stats.most_frequent() {
+        let percentage = 100.0 * count as f64 / total as f64;
+        writeln!(
+            out,
+            "{:<20} {:>8} {:>7.2}",
+            accent.english_name(),
+            count,
+            percentage
+        )?;
+    }
+    writeln!(out, "{:<20} {:>8}", "total", total)
+}
+
+/// Writes the accent table to stdout
+fn tables(table: Table, format: Format) -> io::Result<()> {
+    let table = match table {
+        Table::Prose => AccentTable::Prose,
+        Table::Poetry => AccentTable::Poetry,
+        Table::Pseudo => AccentTable::Pseudo,
+    };
+    write_accent_table(&mut io::stdout().lock(), table, table_format(format))
+}
+
+fn table_format(format: Format) -> TableFormat {
+    match format {
+        Format::Json => TableFormat::Json,
+        Format::Csv => TableFormat::Csv,
+        Format::Markdown => TableFormat::Markdown,
+    }
+}
+
+impl Input {
+    /// The context of all verses, None if it follows the references
+    fn context(&self) -> Option<Context> {
+        if self.poetic {
+            Some(Context::Poetic)
+        } else if self.prosaic || !self.tsv {
+            Some(Context::Prosaic)
+        } else {
+            None
+        }
+    }
+
+    /// Reads the verses of the files, or of stdin
+    ///
+    /// Malformed lines are reported on stderr and skipped.
+    fn read(&self) -> io::Result<Vec<Verse>> {
+        let format = if self.tsv {
+            CorpusFormat::Tsv
+        } else {
+            CorpusFormat::Plain
+        };
+        let mut verses = Vec::new();
+        let stdin = [PathBuf::from("-")];
+        let files = if self.files.is_empty() {
+            &stdin[..]
+        } else {
+            &self.files[..]
+        };
+        for file in files {
+            let (source, text) = if file.as_os_str() == "-" {
+                let mut text = String::new();
+                for line in io::stdin().lock().lines() {
+                    text.push_str(&line?);
+                    text.push('\n');
+                }
+                ("(stdin)".to_string(), text)
+            } else {
+                (file.display().to_string(), fs::read_to_string(file)?)
+            };
+            let mut reader = CorpusReader::new(text.as_bytes(), format);
+            if let Some(ctx) = self.context() {
+                reader = reader.context(ctx);
+            }
+            for verse in reader {
+                match verse {
+                    Ok(verse) => verses.push(Verse {
+                        source: source.clone(),
+                        line: verse.line,
+                        reference: verse.reference,
+                        sc: verse.sc,
+                    }),
+                    Err(err) => eprintln!("hebrew-accents: {}: {}", source, err),
+                }
+            }
+        }
+        Ok(verses)
+    }
+}
+
+impl Verse {
+    /// Reports the error of the verse on stderr and returns the fallback
+    fn report<T>(&self, err: Error, fallback: T) -> T {
+        eprintln!("hebrew-accents: {}:{}: {}", self.source, self.line, err);
+        fallback
+    }
+}
+
+fn invalid_input(err: Error) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, err.to_string())
+}
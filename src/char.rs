@@ -22,7 +22,7 @@ pub const PASHTA: char = '\u{0599}';
 pub const YETIV: char = '\u{059A}';
 pub const TEVIR: char = '\u{059B}';
 pub const GERESH: char = '\u{059C}';
-//pub const GERESH_MUQDAM:char = '\u{059D}';
+pub const GERESH_MUQDAM: char = '\u{059D}';
 pub const GERSHAYIM: char = '\u{059E}';
 pub const QARNEY_PARA: char = '\u{059F}';
 pub const TELISHA_GEDOLA: char = '\u{05A0}';
@@ -42,9 +42,59 @@ pub const DEHI: char = '\u{05AD}';
 pub const ZINOR: char = '\u{05AE}';
 pub const METEG: char = '\u{05BD}';
 pub const MAQAF: char = '\u{05BE}';
-//pub const RAFE:char = '\u{05BF}';
+pub const RAFE: char = '\u{05BF}';
 pub const PASEQ: char = '\u{05C0}';
 pub const SOF_PASUQ: char = '\u{05C3}';
+// vowel points and other marks
+pub const SHEVA: char = '\u{05B0}';
+pub const HATAF_SEGOL: char = '\u{05B1}';
+pub const HATAF_PATAH: char = '\u{05B2}';
+pub const HATAF_QAMATS: char = '\u{05B3}';
+pub const HIRIQ: char = '\u{05B4}';
+pub const TSERE: char = '\u{05B5}';
+pub const SEGOL_POINT: char = '\u{05B6}';
+pub const PATAH: char = '\u{05B7}';
+pub const QAMATS: char = '\u{05B8}';
+pub const HOLAM: char = '\u{05B9}';
+pub const HOLAM_HASER_FOR_VAV: char = '\u{05BA}';
+pub const QUBUTS: char = '\u{05BB}';
+/// also used as Mappiq and Shureq
+pub const DAGESH: char = '\u{05BC}';
+pub const QAMATS_QATAN: char = '\u{05C7}';
+pub const SHIN_DOT: char = '\u{05C1}';
+pub const SIN_DOT: char = '\u{05C2}';
+pub const UPPER_DOT: char = '\u{05C4}';
+pub const LOWER_DOT: char = '\u{05C5}';
+// letters
+pub const ALEF: char = '\u{05D0}';
+pub const BET: char = '\u{05D1}';
+pub const GIMEL: char = '\u{05D2}';
+pub const DALET: char = '\u{05D3}';
+pub const HE: char = '\u{05D4}';
+pub const VAV: char = '\u{05D5}';
+pub const ZAYIN: char = '\u{05D6}';
+pub const HET: char = '\u{05D7}';
+pub const TET: char = '\u{05D8}';
+pub const YOD: char = '\u{05D9}';
+pub const FINAL_KAF: char = '\u{05DA}';
+pub const KAF: char = '\u{05DB}';
+pub const LAMED: char = '\u{05DC}';
+pub const FINAL_MEM: char = '\u{05DD}';
+pub const MEM: char = '\u{05DE}';
+pub const FINAL_NUN: char = '\u{05DF}';
+pub const NUN: char = '\u{05E0}';
+/// also used as closed section marker (Setumah)
+pub const SAMEKH: char = '\u{05E1}';
+pub const AYIN: char = '\u{05E2}';
+pub const FINAL_PE: char = '\u{05E3}';
+/// also used as open section marker (Petuchah)
+pub const PE: char = '\u{05E4}';
+pub const FINAL_TSADI: char = '\u{05E5}';
+pub const TSADI: char = '\u{05E6}';
+pub const QOF: char = '\u{05E7}';
+pub const RESH: char = '\u{05E8}';
+pub const SHIN: char = '\u{05E9}';
+pub const TAV: char = '\u{05EA}';
 /// Sometimes used as a PASEQ in computer text
 pub const VERTICAL_LINE: char = '\u{007C}';
 // list of aliases
@@ -0,0 +1,210 @@
+//! Reading line-oriented verse corpora (plain text and tab-separated values)
+//!
+//! Two formats are supported, see [`CorpusFormat`]:
+//!
+//! - tab-separated values: `book<TAB>chapter<TAB>verse<TAB>text`, the book by
+//!   name or abbreviation (see [`Book`](crate::Book)), e.g. `Gen<TAB>1<TAB>1<TAB>בְּרֵאשִׁ֖ית…`
+//! - plain text: one verse per line, optionally ending with the section
+//!   markers `׃ פ` or `׃ ס`, without references
+//!
+//! Empty lines and lines starting with `#` are skipped, a byte order mark and
+//! Windows line endings are removed. A malformed line is reported as an
+//! [`Error::CorpusLine`] with its line number, after which reading continues
+//! with the next line.
+
+// Standard library
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Lines};
+use std::path::Path;
+
+// External crates
+
+// Crate‑internal (local modules)
+use crate::normalize;
+use crate::{Book, Context, Error, Normalization, Reference, SentenceContext};
+
+/// The format of the lines of a corpus
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum CorpusFormat {
+    /// `book<TAB>chapter<TAB>verse<TAB>text`
+    Tsv,
+    /// One verse per line, without references
+    Plain,
+}
+
+/// A verse read from a corpus
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CorpusVerse {
+    /// The line number, starting at 1
+    pub line: usize,
+    /// The reference of the verse, None for plain text
+    pub reference: Option<Reference>,
+    /// The verse and its context
+    pub sc: SentenceContext,
+}
+
+/// Iterator over the verses of a line-oriented corpus
+///
+/// The context of a verse follows its reference (see [`Reference::context`]),
+/// plain text is prosaic unless another context is set with
+/// [`context`](CorpusReader::context).
+///
+/// # Example
+/// ```
+/// use hebrew_accents::{Context, CorpusFormat, CorpusReader, Error};
+///
+/// let tsv = "Gen\t1\t1\tבְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים\nPs\t23\t1\tמִזְמ֥וֹר לְדָוִ֑ד\nPs\tx\t2\tא\n";
+/// let verses: Vec<_> = CorpusReader::new(tsv.as_bytes(), CorpusFormat::Tsv).collect();
+/// let first = verses[0].as_ref().unwrap();
+/// assert_eq!(first.reference.unwrap().to_string(), "Gen 1:1");
+/// assert_eq!(verses[1].as_ref().unwrap().sc.ctx, Context::Poetic);
+/// assert!(matches!(verses[2], Err(Error::CorpusLine { line: 3, .. })));
+/// ```
+pub struct CorpusReader<R> {
+    lines: Lines<R>,
+    format: CorpusFormat,
+    /// The context of all verses, instead of the context of the reference
+    context: Option<Context>,
+    /// Skip the first line
+    header: bool,
+    /// The mark order of the verses
+    normalization: Normalization,
+    /// The number of the last line read
+    line: usize,
+    /// Stop after an I/O error
+    done: bool,
+}
+
+impl CorpusReader<BufReader<File>> {
+    /// Opens the corpus file
+    ///
+    /// # Errors
+    /// Returns [`Error::Corpus`] if the file can not be opened.
+    pub fn open<P: AsRef<Path>>(path: P, format: CorpusFormat) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let file = File::open(path)
+            .map_err(|err| Error::Corpus(format!("{}: {}", path.display(), err)))?;
+        Ok(CorpusReader::new(BufReader::new(file), format))
+    }
+}
+
+impl<R: BufRead> CorpusReader<R> {
+    /// Creates a reader of the corpus in the format
+    pub fn new(reader: R, format: CorpusFormat) -> Self {
+        CorpusReader {
+            lines: reader.lines(),
+            format,
+            context: None,
+            header: false,
+            normalization: Normalization::None,
+            line: 0,
+            done: false,
+        }
+    }
+
+    /// Uses the context for all verses, e.g. [`Context::Poetic`] for a plain
+    /// text of the Psalms
+    pub fn context(mut self, ctx: Context) -> Self {
+        self.context = Some(ctx);
+        self
+    }
+
+    /// Skips the first line (a header with the names of the columns)
+    pub fn header(mut self, header: bool) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// Brings the marks of the verses into the order, e.g.
+    /// [`Normalization::Canonical`] for sources with the accents before the vowels
+    pub fn normalization(mut self, normalization: Normalization) -> Self {
+        self.normalization = normalization;
+        self
+    }
+
+    /// Parses a (non-empty) line
+    fn parse_line(&self, text: &str) -> Result<CorpusVerse, String> {
+        let (reference, text) = match self.format {
+            CorpusFormat::Plain => (None, text),
+            CorpusFormat::Tsv => {
+                let fields: Vec<&str> = text.splitn(4, '\t').collect();
+                let [book, chapter, verse, text] = fields[..] else {
+                    return Err(
+                        "expected 4 tab-separated fields: book, chapter, verse and text"
+                            .to_string(),
+                    );
+                };
+                let book: Book = book.trim().parse().map_err(|err: Error| err.to_string())?;
+                let chapter = chapter
+                    .trim()
+                    .parse()
+                    .map_err(|_| format!("invalid chapter: {}", chapter))?;
+                let verse = verse
+                    .trim()
+                    .parse()
+                    .map_err(|_| format!("invalid verse: {}", verse))?;
+                let reference =
+                    Reference::new(book, chapter, verse).map_err(|err| err.to_string())?;
+                (Some(reference), text)
+            }
+        };
+        let text = text.trim();
+        if text.is_empty() {
+            return Err("the verse is empty".to_string());
+        }
+        let ctx = self
+            .context
+            .or(reference.map(|reference| reference.context()))
+            .unwrap_or_default();
+        Ok(CorpusVerse {
+            line: self.line,
+            reference,
+            sc: SentenceContext::new(&normalize::apply(text, self.normalization), ctx),
+        })
+    }
+}
+
+impl<R: BufRead> Iterator for CorpusReader<R> {
+    type Item = Result<CorpusVerse, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            let next = self.lines.next()?;
+            self.line += 1;
+            let line = match next {
+                Ok(line) => line,
+                Err(err) if err.kind() == io::ErrorKind::InvalidData => {
+                    return Some(Err(self.line_error("the line is not valid UTF-8")));
+                }
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(Error::Corpus(format!("line {}: {}", self.line, err))));
+                }
+            };
+            let mut text = line.trim_end_matches('\r');
+            if self.line == 1 {
+                text = text.trim_start_matches('\u{FEFF}');
+                if self.header {
+                    continue;
+                }
+            }
+            if text.trim().is_empty() || text.starts_with('#') {
+                continue;
+            }
+            return Some(
+                self.parse_line(text)
+                    .map_err(|message| self.line_error(&message)),
+            );
+        }
+        None
+    }
+}
+
+impl<R> CorpusReader<R> {
+    fn line_error(&self, message: &str) -> Error {
+        Error::CorpusLine {
+            line: self.line,
+            message: message.to_string(),
+        }
+    }
+}
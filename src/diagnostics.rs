@@ -0,0 +1,76 @@
+//! Diagnostics on the accentuation of a sentence
+//!
+
+// Standard library
+use std::fmt;
+use std::ops::Range;
+
+// External crates
+
+// Crate‑internal (local modules)
+use crate::{Accent, HebrewAccent, Naming};
+
+/// A suspect sequence of conjunctive accents (servi) before a disjunctive accent
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Diagnostic<'h> {
+    /// The sentence in which the servi are found
+    pub haystack: &'h str,
+    /// Start byte of the first servus
+    pub start: usize,
+    /// End byte of the last servus
+    pub end: usize,
+    /// The disjunctive accent (the lord)
+    pub accent: HebrewAccent,
+    /// The servi found before the disjunctive accent, in reading order
+    pub servi: Vec<HebrewAccent>,
+}
+
+impl<'h> Diagnostic<'h> {
+    /// Returns the byte offset of the start of the servi in the haystack
+    #[inline]
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the byte offset of the end of the servi in the haystack
+    #[inline]
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Returns the range over the starting and ending byte offsets of the servi
+    #[inline]
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Returns the text of the servi
+    #[inline]
+    pub fn as_str(&self) -> &'h str {
+        &self.haystack[self.range()]
+    }
+
+    /// Returns the message with the names of the accents in the naming,
+    /// e.g. "unexpected servi Merkha, Munach before Etnachta at 10..25"
+    pub fn message_in(&self, naming: Naming) -> String {
+        let servi: Vec<&str> = self
+            .servi
+            .iter()
+            .map(|servus| servus.display_name(naming))
+            .collect();
+        format!(
+            "unexpected servi {} before {} at {}..{}",
+            servi.join(", "),
+            self.accent.display_name(naming),
+            self.start,
+            self.end
+        )
+    }
+}
+
+/// e.g. "unexpected servi Merkha, Munach before Atnach at 10..25"
+impl fmt::Display for Diagnostic<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message_in(Naming::Bhs))
+    }
+}
@@ -0,0 +1,99 @@
+// Standard library
+use std::fmt;
+
+// External crates
+
+// Local modules / crate‑internal
+use crate::{Context, HebrewAccent};
+
+/// The error type for the fallible routines of this crate,
+/// e.g. [`SentenceContext::try_find_accent`](crate::SentenceContext::try_find_accent)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The backtracking limit of a regular expression was exceeded while searching
+    /// (very long sentences with many accents)
+    BacktrackLimitExceeded,
+    /// Any other failure of a regular expression while searching
+    Regex(String),
+    /// The sentence can not be searched, e.g. it is empty
+    InvalidInput(String),
+    /// The accent does not belong to the accent system of the context,
+    /// e.g. a prose accent in a poetic sentence
+    ContextMismatch {
+        /// The requested accent
+        accent: HebrewAccent,
+        /// The context of the sentence
+        ctx: Context,
+    },
+    /// The name is not the name of any accent
+    UnknownAccent(String),
+    /// The name is the name of more than one accent
+    AmbiguousAccent {
+        /// The requested name
+        name: String,
+        /// The accents with that name
+        candidates: Vec<HebrewAccent>,
+    },
+    /// The text is not a valid Bible reference, e.g. an unknown book or a
+    /// chapter beyond the end of the book
+    InvalidReference(String),
+    /// The corpus can not be read, e.g. the file does not exist or is malformed
+    Corpus(String),
+    /// A line of the corpus is malformed
+    CorpusLine {
+        /// The line number, starting at 1
+        line: usize,
+        /// What is wrong with the line
+        message: String,
+    },
+    /// The text is not valid Michigan-Claremont, or can not be encoded
+    InvalidTransliteration {
+        /// The byte offset of the invalid character
+        position: usize,
+        /// What is wrong with the character
+        message: String,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::BacktrackLimitExceeded => {
+                write!(f, "backtrack limit exceeded while searching the sentence")
+            }
+            Error::Regex(msg) => write!(f, "regular expression failed: {}", msg),
+            Error::InvalidInput(msg) => write!(f, "invalid input: {}", msg),
+            Error::ContextMismatch { accent, ctx } => {
+                write!(f, "accent {:?} does not apply to context {:?}", accent, ctx)
+            }
+            Error::UnknownAccent(name) => write!(f, "unknown accent name: {}", name),
+            Error::AmbiguousAccent { name, candidates } => {
+                write!(f, "ambiguous accent name {}: {:?}", name, candidates)
+            }
+            Error::InvalidReference(text) => write!(f, "invalid Bible reference: {}", text),
+            Error::Corpus(msg) => write!(f, "can not read the corpus: {}", msg),
+            Error::CorpusLine { line, message } => write!(f, "line {}: {}", line, message),
+            Error::InvalidTransliteration { position, message } => {
+                write!(
+                    f,
+                    "invalid transliteration at byte {}: {}",
+                    position, message
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<fancy_regex::Error> for Error {
+    fn from(err: fancy_regex::Error) -> Self {
+        match err {
+            fancy_regex::Error::RuntimeError(fancy_regex::RuntimeError::BacktrackLimitExceeded) => {
+                Error::BacktrackLimitExceeded
+            }
+            other => Error::Regex(other.to_string()),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! HTML output with highlighted accents
+//!
+
+// Standard library
+
+// External crates
+
+// Crate‑internal (local modules)
+use crate::{Accent, AccentCategory, HebrewAccent, Naming};
+
+/// The stylesheet for the output of [`SentenceContext::to_html`](crate::SentenceContext::to_html),
+/// coloring the accents by category
+pub const DEFAULT_STYLESHEET: &str = "\
+.hebrew-accents { direction: rtl; unicode-bidi: isolate; font-size: 1.5em; line-height: 2; }
+.hebrew-accents .accent.disjunctive { color: #b22222; }
+.hebrew-accents .accent.conjunctive { color: #1e6bb8; }
+.hebrew-accents .accent.pseudo { color: #6b6b6b; }
+";
+
+/// What the `<span>` of an accent wraps
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub enum HtmlSpans {
+    /// The word carrying the accent, a word with more than one accent
+    /// is marked with its principal accent (disjunctive before conjunctive)
+    #[default]
+    Word,
+    /// The consonant(s) carrying the code-point(s) of the accent, i.e. the
+    /// grapheme clusters of [`Match::grapheme_range`](crate::Match::grapheme_range),
+    /// a consonant with more than one accent is wrapped in nested spans
+    Mark,
+}
+
+/// Returns the opening `<span>` tag for the accent, with its name in the naming
+pub(crate) fn open_span(accent: HebrewAccent, naming: Naming) -> String {
+    let category = match accent.category() {
+        Some(AccentCategory::Conjunctive) => "conjunctive",
+        Some(AccentCategory::Disjunctive) => "disjunctive",
+        None => "pseudo",
+    };
+    let details = accent.details();
+    let mut tooltip = format!("{}: {}", accent.display_name(naming), details.meaning);
+    if let Some(comment) = details.comment {
+        tooltip.push_str(" (");
+        tooltip.push_str(comment);
+        tooltip.push(')');
+    }
+    format!(
+        "<span class=\"accent {}\" data-accent=\"{}\" data-category=\"{}\" data-strength=\"{}\" title=\"{}\">",
+        category,
+        escape(accent.display_name(naming)),
+        category,
+        accent.relative_strength(),
+        escape(&tooltip)
+    )
+}
+
+/// Escapes the text for use in HTML content and attribute values
+pub(crate) fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
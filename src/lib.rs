@@ -142,6 +142,21 @@
 //! ```
 //!
 //!
+//! ## Cargo features
+//!
+//! - `tracing` (off by default): emit [tracing](<https://docs.rs/tracing>) debug events that record
+//!   which regex or scanner decided the classification of an accent, useful for debugging
+//!   misclassifications. Without this feature the library does not produce any output.
+//! - `serde` (off by default): implement [serde](<https://docs.rs/serde>) `Serialize` and
+//!   `Deserialize` for the public types, e.g. `HebrewAccent`, `SentenceContext` and `Context`.
+//!   Accents are represented by their name (e.g. `{"Prose": "Atnach"}`), a `Match` by its
+//!   accent, offsets and text. The static accent data (`AccentInfo`) is serialized only.
+//! - `cli` (off by default): build the command-line binary `hebrew-accents` with the
+//!   subcommands `grep`, `analyze`, `stats` and `tables`.
+//! - `osis` (off by default): read the verses of OSIS XML files, e.g. the Westminster
+//!   Leningrad Codex of the [Open Scriptures Hebrew Bible](<https://github.com/openscriptures/morphhb>),
+//!   with `OsisReader`.
+//!
 //! ## Releases
 //!
 //! For an overview of released versions see [releases](<https:://github.com/Roestdev/hebrew_accents/releases>).   
@@ -156,31 +171,99 @@
 //!
 //! ## Panics
 //!
-//! TODO
+//! Some accents are found with regular expressions that use backtracking (look-around).
+//! For extremely long sentences the backtracking limit can be exceeded, in which case
+//! `contains_accent()`, `find_accent()`, `find_iter()` and `analyze()` panic.
+//! Use `try_contains_accent()`, `try_find_accent()`, `try_find_iter()` or `try_analyze()` to
+//! handle this as an error instead.
 //!
 //! ## Errors
 //!
-//! TODO
+//! The fallible routines return a [`Result`] with the crate's [`Error`] type:
+//!
+//! - [`Error::BacktrackLimitExceeded`]: the backtracking limit of a regular expression was exceeded
+//! - [`Error::InvalidInput`]: the sentence can not be searched, e.g. it is empty
+//! - [`Error::ContextMismatch`]: the accent does not apply to the context of the sentence,
+//!   e.g. a poetry accent in a prosaic sentence
+//!
+//! ``` rust
+//! use hebrew_accents::{Context, Error, PoetryAccent, SentenceContext};
+//!
+//! let newsc = SentenceContext::new("ויּ֣ר֖א עשׂ֔ו כּ֥י רע֖ות׃", Context::Prosaic);
+//! assert!(matches!(
+//!     newsc.try_find_accent(PoetryAccent::Dechi.into()),
+//!     Err(Error::ContextMismatch { .. })
+//! ));
+//! ```
 
 #![warn(missing_docs)]
 #[cfg_attr(doctest, doc = include_str!("../README.md"))]
 // common items
 mod char;
+mod corpus;
+mod diagnostics;
+mod error;
+mod html;
+mod michigan_claremont;
+mod naming;
+mod normalize;
+#[cfg(feature = "osis")]
+mod osis;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod trace;
 
 // finding Hebrew Accents
+mod parse_tree;
+mod reference;
 mod sentence_context; // main entry
+mod sentence_ctx_analyze;
+mod sentence_ctx_ansi;
 mod sentence_ctx_contains;
 mod sentence_ctx_find;
+mod sentence_ctx_find_iter;
 mod sentence_ctx_funcs;
+mod sentence_ctx_html;
+mod sentence_ctx_matcher;
+mod sentence_ctx_parse_tree;
 mod sentence_ctx_regex;
+mod sentence_ctx_render;
+mod sentence_ctx_validate;
+mod sentence_ctx_words;
+mod statistics;
+mod syllable;
+mod word;
 
 // static 'Hebrew Accent' data
 mod accent; // main entry
+mod accent_chars;
 mod accent_codepoints;
 mod accent_data;
 mod accent_display;
+mod accent_export;
+mod accent_filter;
+mod accent_parse;
+mod ansi;
 
 // exports
 pub use accent::*;
+pub use accent_chars::*;
 pub use accent_display::*;
+pub use accent_export::*;
+pub use accent_filter::*;
+pub use ansi::*;
+pub use corpus::*;
+pub use diagnostics::*;
+pub use error::*;
+pub use html::*;
+pub use michigan_claremont::*;
+pub use naming::*;
+pub use normalize::*;
+#[cfg(feature = "osis")]
+pub use osis::*;
+pub use parse_tree::*;
+pub use reference::*;
 pub use sentence_context::*;
+pub use statistics::*;
+pub use syllable::*;
+pub use word::*;
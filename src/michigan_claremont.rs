@@ -0,0 +1,434 @@
+//! Michigan-Claremont (CCAT) and Westminster transliteration
+//!
+//! The Michigan-Claremont encoding writes pointed Hebrew in ASCII, e.g.
+//! `B.:R")$I73YT` for בְּרֵאשִׁ֖ית. The Westminster Hebrew Morphology uses the
+//! same encoding. Every mark follows its consonant:
+//!
+//! - consonants: `) B G D H W Z X + Y K L M N S ( P C Q R T`, Shin `$`, Sin `&`
+//!   and undotted Shin/Sin `#`; there are no final forms
+//! - vowels: `A` Patach, `F` Qamats, `E` Segol, `"` Tsere, `I` Hiriq, `O` Holam,
+//!   `U` Qubuts, `:` Sheva, `:A` `:F` `:E` Chateph Patach, Qamats and Segol
+//! - `.` Dagesh (Mappiq, Shureq), `,` Rafe and `-` Maqqeph
+//! - accents: two-digit codes, e.g. `92` Atnach, `73` Tiphcha and `75` Silluq
+//!   (Meteg), `00` Sof Pasuq and `05` Paseq
+//!
+//! Some codes tell apart forms that are the same Unicode mark, e.g. `03`
+//! (postpositive Pashta) and `33` (Pashta on the stressed syllable), or `35`,
+//! `75` and `95` (Meteg). The encoder chooses the postpositive (prepositive)
+//! code for a mark on the last (first) consonant of a word, and `75` for Meteg.
+
+// Standard library
+
+// External crates
+use unicode_normalization::UnicodeNormalization;
+
+// Crate‑internal (local modules)
+use crate::accent_chars::accents_for_char;
+use crate::char::{
+    ALEF, AYIN, BET, DAGESH, DALET, DARGA, DEHI, ETNAHTA, FINAL_KAF, FINAL_MEM, FINAL_NUN,
+    FINAL_PE, FINAL_TSADI, GERESH, GERESH_MUQDAM, GERSHAYIM, GIMEL, HATAF_PATAH, HATAF_QAMATS,
+    HATAF_SEGOL, HE, HET, HIRIQ, HOLAM, HOLAM_HASER_FOR_VAV, ILUY, KAF, LAMED, MAHPAKH, MAQAF, MEM,
+    MERKHA, MERKHA_KEFULA, METEG, MUNAH, NUN, OLEH, PASEQ, PASHTA, PATAH, PAZER, PE, QADMA, QAMATS,
+    QAMATS_QATAN, QARNEY_PARA, QOF, QUBUTS, RAFE, RESH, REVIA, SAMEKH, SEGOL, SEGOL_POINT,
+    SHALSHELET, SHEVA, SHIN, SHIN_DOT, SIN_DOT, SOF_PASUQ, TAV, TELISHA_GEDOLA, TELISHA_QETANA,
+    TET, TEVIR, TIPEHA, TSADI, TSERE, VAV, VERTICAL_LINE, YERAH_BEN_YOMO, YETIV, YOD, ZAQEF_GADOL,
+    ZAQEF_QATAN, ZARQA, ZAYIN, ZINOR,
+};
+use crate::{normalize, Context, Error, HebrewAccent, SentenceContext};
+
+/// The consonants (Shin and Sin with their dots are `$` and `&`)
+const CONSONANTS: [(char, char); 22] = [
+    (')', ALEF),
+    ('B', BET),
+    ('G', GIMEL),
+    ('D', DALET),
+    ('H', HE),
+    ('W', VAV),
+    ('Z', ZAYIN),
+    ('X', HET),
+    ('+', TET),
+    ('Y', YOD),
+    ('K', KAF),
+    ('L', LAMED),
+    ('M', MEM),
+    ('N', NUN),
+    ('S', SAMEKH),
+    ('(', AYIN),
+    ('P', PE),
+    ('C', TSADI),
+    ('Q', QOF),
+    ('R', RESH),
+    ('#', SHIN),
+    ('T', TAV),
+];
+
+/// The final forms of the consonants
+const FINAL_FORMS: [(char, char); 5] = [
+    (KAF, FINAL_KAF),
+    (MEM, FINAL_MEM),
+    (NUN, FINAL_NUN),
+    (PE, FINAL_PE),
+    (TSADI, FINAL_TSADI),
+];
+
+/// The vowels, Dagesh and Rafe (the Chateph vowels before the Sheva)
+const POINTS: [(&str, char); 13] = [
+    (":A", HATAF_PATAH),
+    (":F", HATAF_QAMATS),
+    (":E", HATAF_SEGOL),
+    (":", SHEVA),
+    ("A", PATAH),
+    ("F", QAMATS),
+    ("E", SEGOL_POINT),
+    ("\"", TSERE),
+    ("I", HIRIQ),
+    ("O", HOLAM),
+    ("U", QUBUTS),
+    (".", DAGESH),
+    (",", RAFE),
+];
+
+/// The accent codes, the first code of a mark is its default code
+const ACCENT_CODES: [(&str, char); 38] = [
+    ("00", SOF_PASUQ),
+    ("01", SEGOL),
+    ("02", ZINOR),
+    ("03", PASHTA),
+    ("04", TELISHA_QETANA),
+    ("05", PASEQ),
+    ("10", YETIV),
+    ("11", GERESH_MUQDAM),
+    ("13", DEHI),
+    ("14", TELISHA_GEDOLA),
+    ("24", TELISHA_QETANA),
+    ("33", PASHTA),
+    ("35", METEG),
+    ("44", TELISHA_GEDOLA),
+    ("60", OLEH),
+    ("61", GERESH),
+    ("62", GERSHAYIM),
+    ("63", QADMA),
+    ("64", ILUY),
+    ("65", SHALSHELET),
+    ("70", MAHPAKH),
+    ("71", MERKHA),
+    ("72", MERKHA_KEFULA),
+    ("73", TIPEHA),
+    ("74", MUNAH),
+    ("75", METEG),
+    ("80", ZAQEF_QATAN),
+    ("81", REVIA),
+    ("82", ZARQA),
+    ("83", PAZER),
+    ("84", QARNEY_PARA),
+    ("85", ZAQEF_GADOL),
+    ("91", TEVIR),
+    ("92", ETNAHTA),
+    ("93", YERAH_BEN_YOMO),
+    ("94", DARGA),
+    ("95", METEG),
+    // a Paseq written as a vertical line
+    ("05", VERTICAL_LINE),
+];
+
+/// Returns the accents of the code, taking into account the context
+///
+/// # Example
+/// ```
+/// use hebrew_accents::{accents_for_mc_code, Context, PoetryAccent, ProseAccent};
+///
+/// assert_eq!(accents_for_mc_code("92", Context::Prosaic), [ProseAccent::Atnach.into()]);
+/// assert_eq!(accents_for_mc_code("92", Context::Poetic), [PoetryAccent::Atnach.into()]);
+/// assert!(accents_for_mc_code("99", Context::Prosaic).is_empty());
+/// ```
+pub fn accents_for_mc_code(code: &str, ctx: Context) -> &'static [HebrewAccent] {
+    ACCENT_CODES
+        .iter()
+        .find(|(mc, _)| *mc == code)
+        .map_or(&[], |(_, c)| accents_for_char(*c, ctx))
+}
+
+/// Converts a Michigan-Claremont (Westminster) encoded text to Unicode Hebrew
+///
+/// The marks of every consonant are kept in the order of the text, which is
+/// the crate canonical order for regular Michigan-Claremont.
+/// Final forms are used for the last consonant of a word, except for a
+/// single `P` or `S` (the section markers). A Paseq is preceded by a space.
+/// The morpheme separator `/` and the Ketiv and Qere markers `*` are dropped.
+///
+/// # Errors
+/// Returns [`Error::InvalidTransliteration`] with the byte offset of the first
+/// character that is not part of the encoding, e.g. an unknown accent code.
+///
+/// # Example
+/// ```
+/// use hebrew_accents::{decode_michigan_claremont, normalize};
+///
+/// let verse = decode_michigan_claremont("B.:R\")$I73YT B.FRF74) ):ELOHI92YM").unwrap();
+/// assert_eq!(verse, normalize("בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים"));
+/// ```
+pub fn decode_michigan_claremont(text: &str) -> Result<String, Error> {
+    let mut decoded = String::with_capacity(text.len() * 2);
+    let mut word = Word::default();
+    let mut pos = 0;
+    while pos < text.len() {
+        let rest = &text[pos..];
+        let c = rest.chars().next().unwrap_or_default();
+        if let Some((_, letter)) = CONSONANTS.iter().find(|(mc, _)| *mc == c) {
+            word.letter(decoded.len());
+            decoded.push(*letter);
+            pos += 1;
+        } else if c == '$' || c == '&' {
+            word.letter(decoded.len());
+            decoded.push(SHIN);
+            decoded.push(if c == '$' { SHIN_DOT } else { SIN_DOT });
+            pos += 1;
+        } else if let Some((mc, point)) = POINTS.iter().find(|(mc, _)| rest.starts_with(mc)) {
+            word.marks += 1;
+            decoded.push(*point);
+            pos += mc.len();
+        } else if c.is_ascii_digit() {
+            let code = rest
+                .get(..2)
+                .filter(|code| code.bytes().all(|b| b.is_ascii_digit()));
+            let mark = code.and_then(|code| {
+                ACCENT_CODES
+                    .iter()
+                    .find(|(mc, _)| *mc == code)
+                    .map(|(_, mark)| *mark)
+            });
+            match mark {
+                Some(SOF_PASUQ) => {
+                    word.finish(&mut decoded);
+                    decoded.push(SOF_PASUQ);
+                }
+                Some(PASEQ) => {
+                    word.finish(&mut decoded);
+                    push_paseq(&mut decoded);
+                }
+                Some(mark) => {
+                    word.marks += 1;
+                    decoded.push(mark);
+                }
+                None => {
+                    return Err(invalid(
+                        pos,
+                        format!("unknown accent code {}", code.unwrap_or(&rest[..1])),
+                    ))
+                }
+            }
+            pos += 2;
+        } else if c == '-' {
+            word.finish(&mut decoded);
+            decoded.push(MAQAF);
+            pos += 1;
+        } else if c == '|' {
+            word.finish(&mut decoded);
+            push_paseq(&mut decoded);
+            pos += 1;
+        } else if c.is_whitespace() {
+            word.finish(&mut decoded);
+            decoded.push(c);
+            pos += c.len_utf8();
+        } else if c == '/' || c == '*' {
+            pos += 1;
+        } else {
+            return Err(invalid(pos, format!("unknown character {:?}", c)));
+        }
+    }
+    word.finish(&mut decoded);
+    Ok(decoded)
+}
+
+/// Converts Unicode Hebrew to the Michigan-Claremont (Westminster) encoding
+///
+/// The marks of every consonant are written in the crate canonical order (see
+/// [`normalize`](crate::normalize())): Dagesh, Rafe, vowels, Meteg, accents.
+/// Final forms are written as the other forms, Holam Haser for Vav as Holam,
+/// Qamats Qatan as Qamats. Joiners (U+034F, U+200C, U+200D) are dropped.
+///
+/// # Errors
+/// Returns [`Error::InvalidTransliteration`] with the byte offset of the first
+/// character that can not be encoded, e.g. a Latin letter.
+///
+/// # Example
+/// ```
+/// use hebrew_accents::encode_michigan_claremont;
+///
+/// let mc = encode_michigan_claremont("בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים").unwrap();
+/// assert_eq!(mc, "B.:R\")$I73YT B.FRF74) ):ELOHI92YM");
+/// ```
+pub fn encode_michigan_claremont(text: &str) -> Result<String, Error> {
+    for (pos, c) in text.char_indices() {
+        if !c.to_string().nfd().all(encodable) {
+            return Err(invalid(
+                pos,
+                format!("{:?} (U+{:04X}) can not be encoded", c, u32::from(c)),
+            ));
+        }
+    }
+    let chars: Vec<char> = normalize(text).chars().filter(|c| !is_joiner(*c)).collect();
+    let mut encoded = String::with_capacity(text.len());
+    for (idx, c) in chars.iter().enumerate() {
+        let c = *c;
+        if c == SHIN {
+            encoded.push(match chars.get(idx + 1) {
+                Some(&SHIN_DOT) => '$',
+                Some(&SIN_DOT) => '&',
+                _ => '#',
+            });
+        } else if let Some((mc, _)) = CONSONANTS
+            .iter()
+            .find(|(_, letter)| *letter == base_form(c))
+        {
+            encoded.push(*mc);
+        } else if let Some((mc, _)) = POINTS.iter().find(|(_, point)| *point == vowel_form(c)) {
+            encoded.push_str(mc);
+        } else if c == SHIN_DOT || c == SIN_DOT {
+            // part of `$` or `&`
+        } else if c == MAQAF {
+            encoded.push('-');
+        } else if c.is_whitespace() {
+            encoded.push(c);
+        } else {
+            encoded.push_str(accent_code(&chars, idx));
+        }
+    }
+    Ok(encoded)
+}
+
+impl SentenceContext {
+    /// Creates a new SentenceContext from a Michigan-Claremont (Westminster)
+    /// encoded sentence, see [`decode_michigan_claremont`]
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidTransliteration`] if the sentence is not
+    /// Michigan-Claremont encoded.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_accents::{Context, ProseAccent, SentenceContext};
+    ///
+    /// let sc = SentenceContext::from_michigan_claremont(
+    ///     "B.FRF74) ):ELOHI92YM",
+    ///     Context::Prosaic,
+    /// )
+    /// .unwrap();
+    /// assert!(sc.contains_accent(ProseAccent::Atnach.into()));
+    /// assert_eq!(sc.to_michigan_claremont().unwrap(), "B.FRF74) ):ELOHI92YM");
+    /// ```
+    pub fn from_michigan_claremont(sentence: &str, ctx: Context) -> Result<Self, Error> {
+        Ok(SentenceContext::new(
+            &decode_michigan_claremont(sentence)?,
+            ctx,
+        ))
+    }
+
+    /// Returns the sentence in the Michigan-Claremont (Westminster) encoding,
+    /// see [`encode_michigan_claremont`]
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidTransliteration`] if the sentence contains a
+    /// character that can not be encoded.
+    pub fn to_michigan_claremont(&self) -> Result<String, Error> {
+        encode_michigan_claremont(&self.sentence)
+    }
+}
+
+/* helper functions */
+
+/// The consonants and marks of the word being decoded
+#[derive(Default)]
+struct Word {
+    /// Byte offset of the last consonant
+    last: Option<usize>,
+    letters: usize,
+    marks: usize,
+}
+
+impl Word {
+    fn letter(&mut self, offset: usize) {
+        self.last = Some(offset);
+        self.letters += 1;
+    }
+
+    /// Replaces the last consonant with its final form, except a section marker
+    fn finish(&mut self, decoded: &mut String) {
+        let section_marker = self.letters == 1 && self.marks == 0;
+        if let (Some(offset), false) = (self.last, section_marker) {
+            let letter = decoded[offset..].chars().next().unwrap_or_default();
+            if let Some((_, last)) = FINAL_FORMS.iter().find(|(form, _)| *form == letter) {
+                decoded.replace_range(offset..offset + letter.len_utf8(), &last.to_string());
+            }
+        }
+        *self = Word::default();
+    }
+}
+
+/// Appends a Paseq, separated by a space from the preceding word
+fn push_paseq(decoded: &mut String) {
+    if !decoded.is_empty() && !decoded.ends_with(char::is_whitespace) {
+        decoded.push(' ');
+    }
+    decoded.push(PASEQ);
+}
+
+/// The form of a final consonant used in the encoding
+fn base_form(c: char) -> char {
+    FINAL_FORMS
+        .iter()
+        .find(|(_, last)| *last == c)
+        .map_or(c, |(form, _)| *form)
+}
+
+/// The vowel used in the encoding
+fn vowel_form(c: char) -> char {
+    match c {
+        HOLAM_HASER_FOR_VAV => HOLAM,
+        QAMATS_QATAN => QAMATS,
+        c => c,
+    }
+}
+
+fn is_joiner(c: char) -> bool {
+    matches!(c, '\u{034F}' | '\u{200C}' | '\u{200D}')
+}
+
+/// Returns true if the (decomposed) character can be encoded
+fn encodable(c: char) -> bool {
+    c == SHIN
+        || c == SHIN_DOT
+        || c == SIN_DOT
+        || c == MAQAF
+        || c.is_whitespace()
+        || is_joiner(c)
+        || CONSONANTS.iter().any(|(_, letter)| *letter == base_form(c))
+        || POINTS.iter().any(|(_, point)| *point == vowel_form(c))
+        || ACCENT_CODES.iter().any(|(_, mark)| *mark == c)
+}
+
+/// The code of the accent at the index, taking into account its position in
+/// the word
+fn accent_code(chars: &[char], idx: usize) -> &'static str {
+    let is_break =
+        |c: &char| c.is_whitespace() || matches!(*c, MAQAF | SOF_PASUQ | PASEQ | VERTICAL_LINE);
+    let is_letter = |c: &char| ('\u{05D0}'..='\u{05EA}').contains(c);
+    let before = chars[..idx].iter().rev().take_while(|c| !is_break(c));
+    let after = chars[idx + 1..].iter().take_while(|c| !is_break(c));
+    let first = before.filter(|c| is_letter(c)).count() <= 1;
+    let last = !after.clone().any(is_letter);
+    match chars[idx] {
+        METEG => "75",
+        PASHTA if !last => "33",
+        TELISHA_QETANA if !last => "24",
+        TELISHA_GEDOLA if !first => "44",
+        c => ACCENT_CODES
+            .iter()
+            .find(|(_, mark)| *mark == c)
+            .map_or("", |(code, _)| code),
+    }
+}
+
+fn invalid(position: usize, message: String) -> Error {
+    Error::InvalidTransliteration { position, message }
+}
@@ -0,0 +1,82 @@
+//! Naming of the accents according the Jewish traditions
+//!
+//! Besides the BHS names (see [`Accent::english_name`](crate::Accent::english_name)),
+//! the accents are known by other names in the Ashkenazi, Sephardi, Italian
+//! and Yemenite traditions. A [`Naming`] selects the names used by
+//! [`Accent::display_name`](crate::Accent::display_name) and
+//! [`SentenceContext::to_html_in`](crate::SentenceContext::to_html_in).
+
+// Standard library
+
+// External crates
+
+// Crate‑internal (local modules)
+use crate::Tradition;
+
+/// One of the four Jewish traditions naming the accents
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TraditionKind {
+    /// Ashkenazi tradition
+    Ashkenazi,
+    /// Sephardi tradition
+    Sephardi,
+    /// Italian tradition
+    Italian,
+    /// Yemenite tradition
+    Yemenite,
+}
+
+impl TraditionKind {
+    /// All traditions, in the order of the code-point tables
+    pub const ALL: [TraditionKind; 4] = [
+        TraditionKind::Ashkenazi,
+        TraditionKind::Sephardi,
+        TraditionKind::Italian,
+        TraditionKind::Yemenite,
+    ];
+}
+
+impl Tradition {
+    /// Returns the tradition of the name
+    pub fn kind(self) -> TraditionKind {
+        match self {
+            Tradition::Ashkenazi { .. } => TraditionKind::Ashkenazi,
+            Tradition::Sephardi { .. } => TraditionKind::Sephardi,
+            Tradition::Italian { .. } => TraditionKind::Italian,
+            Tradition::Yemenite { .. } => TraditionKind::Yemenite,
+        }
+    }
+
+    /// Returns the Hebrew name of the accent in this tradition
+    pub fn hebrew_name(self) -> &'static str {
+        match self {
+            Tradition::Ashkenazi { hebrew_name, .. }
+            | Tradition::Sephardi { hebrew_name, .. }
+            | Tradition::Italian { hebrew_name, .. }
+            | Tradition::Yemenite { hebrew_name, .. } => hebrew_name,
+        }
+    }
+
+    /// Returns the transliterated English name of the accent in this tradition
+    pub fn english_name(self) -> &'static str {
+        match self {
+            Tradition::Ashkenazi { english_name, .. }
+            | Tradition::Sephardi { english_name, .. }
+            | Tradition::Italian { english_name, .. }
+            | Tradition::Yemenite { english_name, .. } => english_name,
+        }
+    }
+}
+
+/// The names used to present the accents
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Naming {
+    /// The names used in the BHS (Biblia Hebraica Stuttgartensia)
+    #[default]
+    Bhs,
+    /// The names used in one of the Jewish traditions, falling back to the
+    /// BHS name if the tradition has no name for the accent
+    Tradition(TraditionKind),
+}
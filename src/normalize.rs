@@ -0,0 +1,111 @@
+//! Normalization of pointed Hebrew text
+//!
+//! The combining marks of a consonant (points, vowels, Meteg and accents) can
+//! be written in any order, and sources differ: some put the accents before
+//! the vowels, others after. Unicode normalization (NFC/NFD) sorts the marks
+//! by their canonical combining class, which puts e.g. the vowels before the
+//! Dagesh and the Meteg after the Dagesh, an order that is linguistically wrong.
+//!
+//! [`normalize`] brings the text into the crate canonical order, the marks of
+//! every consonant sorted by group:
+//!
+//! 1. Shin dot or Sin dot
+//! 2. Dagesh (Mappiq, Shureq)
+//! 3. Rafe
+//! 4. vowels (including Sheva and Qamats Qatan)
+//! 5. Meteg (Silluq)
+//! 6. accents, the upper and lower dots
+//!
+//! Within a group the Unicode (NFC) order is used. A Combining Grapheme Joiner
+//! (U+034F) stops the reordering, so intentional orders are kept. The
+//! precomposed presentation forms (e.g. U+FB2A SHIN WITH SHIN DOT) are
+//! decomposed.
+//!
+//! The result is not in NFC: normalizing it to NFC again reorders the marks.
+
+// Standard library
+
+// External crates
+use unicode_normalization::UnicodeNormalization;
+
+// Crate‑internal (local modules)
+use crate::char::{
+    DAGESH, LOWER_DOT, METEG, QAMATS_QATAN, QUBUTS, RAFE, SHEVA, SHIN_DOT, SIN_DOT, UPPER_DOT,
+};
+
+/// The mark order of the sentences read by a corpus reader, see e.g.
+/// [`CorpusReader::normalization`](crate::CorpusReader::normalization)
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Normalization {
+    /// Keep the sentence as it is
+    #[default]
+    None,
+    /// Bring the sentence into the crate canonical order, see [`normalize`]
+    Canonical,
+}
+
+/// Brings the text into the crate canonical order (see the module documentation)
+///
+/// # Example
+/// ```
+/// use hebrew_accents::normalize;
+///
+/// // NFC order: Bet, Sheva, Dagesh, Meteg
+/// let nfc = "\u{05D1}\u{05B0}\u{05BC}\u{05BD}";
+/// assert_eq!(normalize(nfc), "\u{05D1}\u{05BC}\u{05B0}\u{05BD}");
+/// // the accent before the vowel
+/// assert_eq!(normalize("\u{05D0}\u{0591}\u{05B8}"), "\u{05D0}\u{05B8}\u{0591}");
+/// // precomposed Shin with Shin dot, followed by a Qamats
+/// assert_eq!(normalize("\u{FB2A}\u{05B8}"), "\u{05E9}\u{05C1}\u{05B8}");
+/// ```
+pub fn normalize(text: &str) -> String {
+    let mut normalized = String::with_capacity(text.len());
+    let mut marks: Vec<char> = Vec::new();
+    for c in text.nfc() {
+        if group(c).is_some() {
+            marks.push(c);
+            continue;
+        }
+        flush(&mut normalized, &mut marks);
+        normalized.push(c);
+    }
+    flush(&mut normalized, &mut marks);
+    normalized
+}
+
+/// Returns true if the text is in the crate canonical order
+pub fn is_normalized(text: &str) -> bool {
+    normalize(text) == text
+}
+
+/// The sentence in the order of the normalization option
+pub(crate) fn apply(sentence: &str, normalization: Normalization) -> String {
+    match normalization {
+        Normalization::None => sentence.to_string(),
+        Normalization::Canonical => normalize(sentence),
+    }
+}
+
+/* helper functions */
+
+/// The group of a combining mark in the canonical order, None for any other
+/// character
+fn group(c: char) -> Option<u8> {
+    match c {
+        SHIN_DOT | SIN_DOT => Some(0),
+        DAGESH => Some(1),
+        RAFE => Some(2),
+        SHEVA..=QUBUTS | QAMATS_QATAN => Some(3),
+        METEG => Some(4),
+        '\u{0591}'..='\u{05AF}' | UPPER_DOT | LOWER_DOT => Some(5),
+        _ => None,
+    }
+}
+
+/// Appends the marks in the canonical order (a stable sort keeps the NFC order
+/// within a group)
+fn flush(normalized: &mut String, marks: &mut Vec<char>) {
+    marks.sort_by_key(|c| group(*c));
+    normalized.extend(marks.drain(..));
+}
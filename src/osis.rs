@@ -0,0 +1,281 @@
+//! Reading OSIS XML corpora (cargo feature `osis`)
+//!
+//! The Open Scriptures Hebrew Bible (OSHB) publishes the Westminster Leningrad
+//! Codex (WLC) as one OSIS file per book. [`OsisReader`] walks such a file and
+//! yields every verse as a [`Reference`] and a [`SentenceContext`] with the
+//! context of the verse (see [`Reference::context`]).
+//!
+//! The text of a verse is rebuilt from its `<w>` and `<seg>` elements: the
+//! morpheme separators (`/`) and the morphology attributes are dropped, the
+//! points and accents are kept. Words are separated by a space, except after a
+//! Maqqeph and before the Sof Pasuq. Notes (e.g. the Qere readings) are
+//! skipped. Both verse containers (`<verse osisID="Gen.1.1">`) and verse
+//! milestones (`<verse sID="Gen.1.1"/>` … `<verse eID="Gen.1.1"/>`) are read.
+
+// Standard library
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+// External crates
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+// Crate‑internal (local modules)
+use crate::char::MAQQEPH;
+use crate::normalize;
+use crate::{Error, Normalization, Reference, SentenceContext};
+
+/// Iterator over the verses of an OSIS XML document
+///
+/// # Example
+/// ```
+/// use hebrew_accents::{Book, Context, OsisReader};
+///
+/// let xml = r#"<osis><osisText><div type="book" osisID="Ps"><chapter osisID="Ps.23">
+///   <verse osisID="Ps.23.1">
+///     <w lemma="4210" morph="HNcmsa">מִזְמ֥וֹר</w>
+///     <w lemma="l/1732" morph="HR/Np">לְ/דָוִ֑ד</w>
+///     <seg type="x-sof-pasuq">׃</seg>
+///   </verse>
+/// </chapter></div></osisText></osis>"#;
+/// let verses: Vec<_> = OsisReader::new(xml.as_bytes())
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+/// let (reference, sc) = &verses[0];
+/// assert_eq!(reference.book, Book::Psalms);
+/// assert_eq!(sc.sentence, "מִזְמ֥וֹר לְדָוִ֑ד׃");
+/// assert_eq!(sc.ctx, Context::Poetic);
+/// ```
+pub struct OsisReader<R> {
+    reader: Reader<R>,
+    buf: Vec<u8>,
+    /// The verse being read and its text
+    verse: Option<(Reference, String)>,
+    /// Depth of the skipped elements (notes)
+    skip: usize,
+    /// Inside a `<w>` or `<seg>` element
+    in_text: bool,
+    /// Stop after the end of the document or an error
+    done: bool,
+    /// The mark order of the verses
+    normalization: Normalization,
+}
+
+/// The parts of an XML event used by the reader
+enum Item {
+    VerseStart(String),
+    VerseEnd,
+    Note,
+    Word,
+    Seg(String),
+    Other,
+    End,
+    Text(String),
+    Eof,
+}
+
+impl OsisReader<BufReader<File>> {
+    /// Opens the OSIS XML file
+    ///
+    /// # Errors
+    /// Returns [`Error::Corpus`] if the file can not be opened.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let file = File::open(path)
+            .map_err(|err| Error::Corpus(format!("{}: {}", path.display(), err)))?;
+        Ok(OsisReader::new(BufReader::new(file)))
+    }
+}
+
+impl<R: BufRead> OsisReader<R> {
+    /// Creates a reader of the OSIS XML document
+    pub fn new(reader: R) -> Self {
+        OsisReader {
+            reader: Reader::from_reader(reader),
+            buf: Vec::new(),
+            verse: None,
+            skip: 0,
+            in_text: false,
+            done: false,
+            normalization: Normalization::None,
+        }
+    }
+
+    /// Brings the marks of the verses into the order, see [`Normalization`]
+    pub fn normalization(mut self, normalization: Normalization) -> Self {
+        self.normalization = normalization;
+        self
+    }
+
+    /// Reads the next event
+    fn read_item(&mut self) -> Result<Item, Error> {
+        self.buf.clear();
+        let event = self
+            .reader
+            .read_event_into(&mut self.buf)
+            .map_err(|err| xml_error(self.reader.buffer_position(), err))?;
+        if self.skip > 0 {
+            match event {
+                Event::Start(_) => self.skip += 1,
+                Event::End(_) => self.skip -= 1,
+                Event::Eof => return Ok(Item::Eof),
+                _ => {}
+            }
+            return Ok(Item::Other);
+        }
+        let item = match event {
+            Event::Start(tag) => start_item(&tag)?,
+            // a verse milestone, or an element without text
+            Event::Empty(tag) if attribute(&tag, b"eID")?.is_some() => match start_item(&tag)? {
+                Item::VerseStart(_) => Item::VerseEnd,
+                _ => Item::Other,
+            },
+            Event::Empty(tag) => match start_item(&tag)? {
+                Item::VerseStart(id) => Item::VerseStart(id),
+                _ => Item::Other,
+            },
+            Event::End(tag) if tag.local_name().as_ref() == b"verse" => Item::VerseEnd,
+            Event::End(_) => Item::End,
+            Event::Text(text) => Item::Text(
+                text.unescape()
+                    .map_err(|err| xml_error(self.reader.buffer_position(), err))?
+                    .into_owned(),
+            ),
+            Event::CData(text) => Item::Text(String::from_utf8_lossy(&text).into_owned()),
+            Event::Eof => Item::Eof,
+            _ => Item::Other,
+        };
+        Ok(item)
+    }
+
+    /// Reads up to the end of the next verse
+    fn read_verse(&mut self) -> Result<Option<(Reference, SentenceContext)>, Error> {
+        loop {
+            match self.read_item()? {
+                Item::VerseStart(id) => {
+                    let reference = osis_reference(&id)?;
+                    if let Some(verse) = self.verse.replace((reference, String::new())) {
+                        return Ok(Some(finish(verse, self.normalization)));
+                    }
+                }
+                Item::VerseEnd => {
+                    if let Some(verse) = self.verse.take() {
+                        return Ok(Some(finish(verse, self.normalization)));
+                    }
+                }
+                Item::Note => self.skip = 1,
+                Item::Word => {
+                    if let Some((_, text)) = &mut self.verse {
+                        separate(text);
+                    }
+                    self.in_text = true;
+                }
+                Item::Seg(kind) => {
+                    if let Some((_, text)) = &mut self.verse {
+                        if kind != "x-maqqef" && kind != "x-sof-pasuq" {
+                            separate(text);
+                        }
+                    }
+                    self.in_text = true;
+                }
+                Item::Text(content) => {
+                    if let (Some((_, text)), true) = (&mut self.verse, self.in_text) {
+                        text.extend(content.chars().filter(|c| *c != '/'));
+                    }
+                }
+                Item::End => self.in_text = false,
+                Item::Other => {}
+                Item::Eof => {
+                    let normalization = self.normalization;
+                    return Ok(self.verse.take().map(|verse| finish(verse, normalization)));
+                }
+            }
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for OsisReader<R> {
+    type Item = Result<(Reference, SentenceContext), Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let verse = self.read_verse().transpose();
+        if !matches!(verse, Some(Ok(_))) {
+            self.done = true;
+        }
+        verse
+    }
+}
+
+/* helper functions */
+
+/// Classifies a start (or empty) tag
+fn start_item(tag: &BytesStart) -> Result<Item, Error> {
+    let item = match tag.local_name().as_ref() {
+        b"verse" => {
+            let id = match attribute(tag, b"osisID")? {
+                Some(id) => id,
+                None => attribute(tag, b"sID")?.unwrap_or_default(),
+            };
+            Item::VerseStart(id)
+        }
+        b"note" => Item::Note,
+        b"w" => Item::Word,
+        b"seg" => Item::Seg(attribute(tag, b"type")?.unwrap_or_default()),
+        _ => Item::Other,
+    };
+    Ok(item)
+}
+
+/// The value of the attribute of the tag
+fn attribute(tag: &BytesStart, name: &[u8]) -> Result<Option<String>, Error> {
+    let value = tag
+        .try_get_attribute(name)
+        .map_err(|err| Error::Corpus(err.to_string()))?;
+    value
+        .map(|attr| {
+            attr.unescape_value()
+                .map(|value| value.into_owned())
+                .map_err(|err| Error::Corpus(err.to_string()))
+        })
+        .transpose()
+}
+
+/// Parses an OSIS verse identifier, e.g. "1Sam.3.4"
+fn osis_reference(id: &str) -> Result<Reference, Error> {
+    let invalid = || Error::Corpus(format!("invalid verse identifier: {}", id));
+    let id = id.split_whitespace().next().ok_or_else(invalid)?;
+    let mut parts = id.split('.');
+    match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(book), Some(chapter), Some(verse), None) => Reference::new(
+            book.parse().map_err(|_| invalid())?,
+            chapter.parse().map_err(|_| invalid())?,
+            verse.parse().map_err(|_| invalid())?,
+        )
+        .map_err(|_| invalid()),
+        _ => Err(invalid()),
+    }
+}
+
+/// Adds a space before the next word, unless the text ends with a Maqqeph
+fn separate(text: &mut String) {
+    if !text.is_empty() && !text.ends_with(MAQQEPH) {
+        text.push(' ');
+    }
+}
+
+fn finish(
+    (reference, text): (Reference, String),
+    normalization: Normalization,
+) -> (Reference, SentenceContext) {
+    let sc =
+        SentenceContext::for_reference(&normalize::apply(text.trim(), normalization), reference);
+    (reference, sc)
+}
+
+fn xml_error(position: u64, err: quick_xml::Error) -> Error {
+    Error::Corpus(format!("XML error at byte {}: {}", position, err))
+}
@@ -0,0 +1,135 @@
+//! The dichotomy tree of a verse (accent parse tree)
+//!
+
+// Standard library
+use std::fmt;
+use std::ops::Range;
+
+// External crates
+
+// Crate‑internal (local modules)
+use crate::{Accent, AccentCategory, AccentType, HebrewAccent, WordGroup};
+
+/// A node of the parse tree: the domain of a disjunctive accent
+///
+/// The domain consists of the subdomains of the lower disjunctives (children),
+/// followed by the conjunctive word groups (servi) and the word group carrying
+/// the disjunctive accent itself (the lord).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseNode<'h> {
+    /// The disjunctive accent ruling the domain, None if the last word group
+    /// has no disjunctive accent (e.g. an incomplete verse)
+    pub accent: Option<HebrewAccent>,
+    /// The word group carrying the disjunctive accent
+    pub lord: WordGroup<'h>,
+    /// The word groups with a conjunctive accent directly preceding the lord
+    pub servi: Vec<WordGroup<'h>>,
+    /// The subdomains dividing the domain, in order of appearance
+    pub children: Vec<ParseNode<'h>>,
+}
+
+impl<'h> ParseNode<'h> {
+    /// Returns the disjunctive accent ruling the domain
+    #[inline]
+    pub fn accent(&self) -> Option<HebrewAccent> {
+        self.accent
+    }
+
+    /// Returns the word group carrying the disjunctive accent
+    #[inline]
+    pub fn lord(&self) -> &WordGroup<'h> {
+        &self.lord
+    }
+
+    /// Returns the word groups with a conjunctive accent serving the lord
+    #[inline]
+    pub fn servi(&self) -> &[WordGroup<'h>] {
+        &self.servi
+    }
+
+    /// Returns the subdomains dividing the domain
+    #[inline]
+    pub fn children(&self) -> &[ParseNode<'h>] {
+        &self.children
+    }
+
+    /// Returns the range over the starting and ending byte offsets of the domain
+    pub fn range(&self) -> Range<usize> {
+        let start = match (self.children.first(), self.servi.first()) {
+            (Some(child), _) => child.range().start,
+            (None, Some(servus)) => servus.start,
+            (None, None) => self.lord.start,
+        };
+        start..self.lord.end
+    }
+
+    /// Returns the text of the domain
+    pub fn as_str(&self) -> &'h str {
+        &self.lord.haystack[self.range()]
+    }
+
+    /// Returns this node and all its descendants (pre-order)
+    pub fn iter(&self) -> impl Iterator<Item = &ParseNode<'h>> {
+        let mut nodes = Vec::new();
+        self.collect_nodes(&mut nodes);
+        nodes.into_iter()
+    }
+
+    /// Returns the number of levels of the tree, a node without children has depth 1
+    pub fn depth(&self) -> usize {
+        1 + self
+            .children
+            .iter()
+            .map(|child| child.depth())
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn collect_nodes<'a>(&'a self, nodes: &mut Vec<&'a ParseNode<'h>>) {
+        nodes.push(self);
+        for child in &self.children {
+            child.collect_nodes(nodes);
+        }
+    }
+
+    fn fmt_indented(&self, f: &mut fmt::Formatter<'_>, indent: usize) -> fmt::Result {
+        let name = self.accent.map_or("-", |accent| accent.english_name());
+        writeln!(
+            f,
+            "{:indent$}{}: {}",
+            "",
+            name,
+            self.lord.as_str(),
+            indent = indent
+        )?;
+        for child in &self.children {
+            child.fmt_indented(f, indent + 2)?;
+        }
+        for servus in &self.servi {
+            let name = servus
+                .accents()
+                .into_iter()
+                .find(|accent| {
+                    accent.category() == Some(AccentCategory::Conjunctive)
+                        && accent.accent_type() == Some(AccentType::Primary)
+                })
+                .map_or("-", |accent| accent.english_name());
+            writeln!(
+                f,
+                "{:indent$}servus {}: {}",
+                "",
+                name,
+                servus.as_str(),
+                indent = indent + 2
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Renders the tree as an indented outline, one word group per line
+impl fmt::Display for ParseNode<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
@@ -0,0 +1,363 @@
+//! Bible references (book, chapter and verse) and their accent system
+//!
+//! The books follow the order of the Hebrew Bible (BHS), the chapters and
+//! verses the Hebrew numbering, e.g. Joel has four chapters and Malachi three.
+//!
+//! Psalms, Proverbs and Job ("the Three Books") use the poetic accent system,
+//! except the prose frame of Job (1:1–3:1 and 42:7–17). All other books use
+//! the prose system, see [`Reference::context`].
+
+// Standard library
+use std::fmt;
+use std::str::FromStr;
+
+// External crates
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+// Crate‑internal (local modules)
+use crate::{Context, Error};
+
+/// Book, chapter and verse, e.g. "1 Sam 3:4", "Ps 23.1" or "Job 3,2"
+static RE_REFERENCE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^\s*(.+?)\s*(\d+)\s*[:.,]\s*(\d+)\s*$").unwrap());
+
+/// A book of the Hebrew Bible, in the order of the BHS
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(missing_docs)]
+pub enum Book {
+    Genesis,
+    Exodus,
+    Leviticus,
+    Numbers,
+    Deuteronomy,
+    Joshua,
+    Judges,
+    FirstSamuel,
+    SecondSamuel,
+    FirstKings,
+    SecondKings,
+    Isaiah,
+    Jeremiah,
+    Ezekiel,
+    Hosea,
+    Joel,
+    Amos,
+    Obadiah,
+    Jonah,
+    Micah,
+    Nahum,
+    Habakkuk,
+    Zephaniah,
+    Haggai,
+    Zechariah,
+    Malachi,
+    Psalms,
+    Job,
+    Proverbs,
+    Ruth,
+    SongOfSongs,
+    Ecclesiastes,
+    Lamentations,
+    Esther,
+    Daniel,
+    Ezra,
+    Nehemiah,
+    FirstChronicles,
+    SecondChronicles,
+}
+
+/// English name, SBL abbreviation, Hebrew name, number of chapters and other
+/// accepted abbreviations of every book, in the order of [`Book::ALL`]
+const BOOK_DATA: [(&str, &str, &str, u16, &[&str]); 39] = [
+    ("Genesis", "Gen", "בראשית", 50, &["Gn"]),
+    ("Exodus", "Exod", "שמות", 40, &["Ex"]),
+    ("Leviticus", "Lev", "ויקרא", 27, &["Lv"]),
+    ("Numbers", "Num", "במדבר", 36, &["Nm", "Nb"]),
+    ("Deuteronomy", "Deut", "דברים", 34, &["Dt"]),
+    ("Joshua", "Josh", "יהושע", 24, &["Jos"]),
+    ("Judges", "Judg", "שופטים", 21, &["Jdg", "Jgs"]),
+    ("1 Samuel", "1 Sam", "שמואל א", 31, &["1 Sm", "1 Sa"]),
+    ("2 Samuel", "2 Sam", "שמואל ב", 24, &["2 Sm", "2 Sa"]),
+    ("1 Kings", "1 Kgs", "מלכים א", 22, &["1 Kg", "1 Ki"]),
+    ("2 Kings", "2 Kgs", "מלכים ב", 25, &["2 Kg", "2 Ki"]),
+    ("Isaiah", "Isa", "ישעיהו", 66, &["Is"]),
+    ("Jeremiah", "Jer", "ירמיהו", 52, &["Jr"]),
+    ("Ezekiel", "Ezek", "יחזקאל", 48, &["Ezk", "Ez"]),
+    ("Hosea", "Hos", "הושע", 14, &[]),
+    ("Joel", "Joel", "יואל", 4, &["Jl"]),
+    ("Amos", "Amos", "עמוס", 9, &["Am"]),
+    ("Obadiah", "Obad", "עבדיה", 1, &["Ob"]),
+    ("Jonah", "Jonah", "יונה", 4, &["Jon"]),
+    ("Micah", "Mic", "מיכה", 7, &[]),
+    ("Nahum", "Nah", "נחום", 3, &[]),
+    ("Habakkuk", "Hab", "חבקוק", 3, &[]),
+    ("Zephaniah", "Zeph", "צפניה", 3, &[]),
+    ("Haggai", "Hag", "חגי", 2, &[]),
+    ("Zechariah", "Zech", "זכריה", 14, &[]),
+    ("Malachi", "Mal", "מלאכי", 3, &[]),
+    ("Psalms", "Ps", "תהלים", 150, &["Psalm", "Psa", "Pss"]),
+    ("Job", "Job", "איוב", 42, &["Jb"]),
+    ("Proverbs", "Prov", "משלי", 31, &["Prv", "Pr"]),
+    ("Ruth", "Ruth", "רות", 4, &["Rt"]),
+    (
+        "Song of Songs",
+        "Song",
+        "שיר השירים",
+        8,
+        &["Song of Solomon", "Cant", "Sg"],
+    ),
+    ("Ecclesiastes", "Eccl", "קהלת", 12, &["Qoh", "Ec"]),
+    ("Lamentations", "Lam", "איכה", 5, &[]),
+    ("Esther", "Esth", "אסתר", 10, &["Est"]),
+    ("Daniel", "Dan", "דניאל", 12, &["Dn"]),
+    ("Ezra", "Ezra", "עזרא", 10, &["Ezr"]),
+    ("Nehemiah", "Neh", "נחמיה", 13, &[]),
+    ("1 Chronicles", "1 Chr", "דברי הימים א", 29, &["1 Chron"]),
+    ("2 Chronicles", "2 Chr", "דברי הימים ב", 36, &["2 Chron"]),
+];
+
+impl Book {
+    /// All books, in the order of the BHS
+    pub const ALL: [Book; 39] = [
+        Book::Genesis,
+        Book::Exodus,
+        Book::Leviticus,
+        Book::Numbers,
+        Book::Deuteronomy,
+        Book::Joshua,
+        Book::Judges,
+        Book::FirstSamuel,
+        Book::SecondSamuel,
+        Book::FirstKings,
+        Book::SecondKings,
+        Book::Isaiah,
+        Book::Jeremiah,
+        Book::Ezekiel,
+        Book::Hosea,
+        Book::Joel,
+        Book::Amos,
+        Book::Obadiah,
+        Book::Jonah,
+        Book::Micah,
+        Book::Nahum,
+        Book::Habakkuk,
+        Book::Zephaniah,
+        Book::Haggai,
+        Book::Zechariah,
+        Book::Malachi,
+        Book::Psalms,
+        Book::Job,
+        Book::Proverbs,
+        Book::Ruth,
+        Book::SongOfSongs,
+        Book::Ecclesiastes,
+        Book::Lamentations,
+        Book::Esther,
+        Book::Daniel,
+        Book::Ezra,
+        Book::Nehemiah,
+        Book::FirstChronicles,
+        Book::SecondChronicles,
+    ];
+
+    /// Returns the English name, e.g. "1 Samuel"
+    pub fn english_name(self) -> &'static str {
+        BOOK_DATA[self as usize].0
+    }
+
+    /// Returns the SBL abbreviation, e.g. "1 Sam"
+    pub fn abbreviation(self) -> &'static str {
+        BOOK_DATA[self as usize].1
+    }
+
+    /// Returns the Hebrew name, e.g. "שמואל א"
+    pub fn hebrew_name(self) -> &'static str {
+        BOOK_DATA[self as usize].2
+    }
+
+    /// Returns the number of chapters (Hebrew numbering)
+    pub fn chapters(self) -> u16 {
+        BOOK_DATA[self as usize].3
+    }
+
+    /// Returns true for Psalms, Proverbs and Job, which use the poetic
+    /// accent system (Job except its prose frame)
+    pub fn is_poetic(self) -> bool {
+        matches!(self, Book::Psalms | Book::Proverbs | Book::Job)
+    }
+}
+
+impl FromStr for Book {
+    type Err = Error;
+
+    /// Parses the English name, an abbreviation or the Hebrew name of a book
+    ///
+    /// Case, spaces and periods are ignored, a leading Roman numeral is
+    /// accepted for the numbered books, and any unambiguous beginning of the
+    /// English name is accepted as well.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_accents::Book;
+    ///
+    /// assert_eq!("Ps".parse::<Book>().unwrap(), Book::Psalms);
+    /// assert_eq!("II Kings".parse::<Book>().unwrap(), Book::SecondKings);
+    /// assert_eq!("1sam".parse::<Book>().unwrap(), Book::FirstSamuel);
+    /// assert_eq!("Deuter".parse::<Book>().unwrap(), Book::Deuteronomy);
+    /// assert_eq!("איוב".parse::<Book>().unwrap(), Book::Job);
+    /// assert!("Jo".parse::<Book>().is_err());
+    /// ```
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let folded = fold(name);
+        if folded.is_empty() {
+            return Err(Error::InvalidReference(name.to_string()));
+        }
+        let exact = Book::ALL.into_iter().find(|book| {
+            let (english, abbreviation, hebrew, _, others) = BOOK_DATA[*book as usize];
+            [english, abbreviation, hebrew]
+                .iter()
+                .chain(others.iter())
+                .any(|alias| fold(alias) == folded)
+        });
+        if let Some(book) = exact {
+            return Ok(book);
+        }
+        let candidates: Vec<Book> = Book::ALL
+            .into_iter()
+            .filter(|book| fold(book.english_name()).starts_with(&folded))
+            .collect();
+        match candidates[..] {
+            [book] => Ok(book),
+            _ => Err(Error::InvalidReference(name.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for Book {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.english_name())
+    }
+}
+
+/// A verse of the Hebrew Bible
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Reference {
+    /// The book
+    pub book: Book,
+    /// The chapter, starting at 1
+    pub chapter: u16,
+    /// The verse, starting at 1
+    pub verse: u16,
+}
+
+impl Reference {
+    /// Creates a reference, checking the chapter against the number of
+    /// chapters of the book
+    ///
+    /// The verse is not range-checked: the number of verses per chapter is not
+    /// known, so e.g. "Ps 23:999" is a valid reference.
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidReference`] if the chapter or verse is 0 or the
+    /// book has fewer chapters.
+    pub fn new(book: Book, chapter: u16, verse: u16) -> Result<Reference, Error> {
+        if chapter == 0 || verse == 0 || chapter > book.chapters() {
+            return Err(Error::InvalidReference(format!(
+                "{} {}:{}",
+                book.abbreviation(),
+                chapter,
+                verse
+            )));
+        }
+        Ok(Reference {
+            book,
+            chapter,
+            verse,
+        })
+    }
+
+    /// Returns the context (accent system) of the verse
+    ///
+    /// Psalms, Proverbs and Job are poetic, except Job 1:1–3:1 and 42:7–17,
+    /// all other books are prosaic.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_accents::{Context, Reference};
+    ///
+    /// let context = |r: &str| r.parse::<Reference>().unwrap().context();
+    /// assert_eq!(context("Ps 23:1"), Context::Poetic);
+    /// assert_eq!(context("Job 3:1"), Context::Prosaic);
+    /// assert_eq!(context("Job 3:2"), Context::Poetic);
+    /// assert_eq!(context("Job 42:7"), Context::Prosaic);
+    /// assert_eq!(context("Gen 1:1"), Context::Prosaic);
+    /// ```
+    pub fn context(&self) -> Context {
+        let prose_frame = self.book == Book::Job
+            && (self.chapter < 3
+                || (self.chapter == 3 && self.verse == 1)
+                || (self.chapter == 42 && self.verse >= 7));
+        if self.book.is_poetic() && !prose_frame {
+            Context::Poetic
+        } else {
+            Context::Prosaic
+        }
+    }
+}
+
+impl FromStr for Reference {
+    type Err = Error;
+
+    /// Parses a reference like "Ps 23:1", "1 Sam 3.4" or "Job 3,2"
+    ///
+    /// Like [`Reference::new`], only the chapter is checked against the book.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_accents::{Book, Reference};
+    ///
+    /// let reference: Reference = "Ps 23:1".parse().unwrap();
+    /// assert_eq!(reference, Reference::new(Book::Psalms, 23, 1).unwrap());
+    /// assert_eq!(reference.to_string(), "Ps 23:1");
+    /// assert!("Ps 151:1".parse::<Reference>().is_err());
+    /// ```
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidReference(text.to_string());
+        let caps = RE_REFERENCE.captures(text).ok_or_else(invalid)?;
+        let book: Book = caps[1].parse().map_err(|_| invalid())?;
+        let chapter = caps[2].parse().map_err(|_| invalid())?;
+        let verse = caps[3].parse().map_err(|_| invalid())?;
+        Reference::new(book, chapter, verse).map_err(|_| invalid())
+    }
+}
+
+impl fmt::Display for Reference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}:{}",
+            self.book.abbreviation(),
+            self.chapter,
+            self.verse
+        )
+    }
+}
+
+/* helper functions */
+
+/// Lowercases the name and removes spaces and periods, a leading Roman
+/// numeral (I or II) becomes a digit
+fn fold(name: &str) -> String {
+    let name = name.trim().to_lowercase();
+    let name = match name.split_once(char::is_whitespace) {
+        Some(("i", rest)) => format!("1{}", rest),
+        Some(("ii", rest)) => format!("2{}", rest),
+        _ => name,
+    };
+    name.chars()
+        .filter(|c| !c.is_whitespace() && *c != '.')
+        .collect()
+}
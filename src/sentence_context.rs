@@ -1,8 +1,16 @@
 //! Main file
 //!
 
+// External crates
+use unicode_segmentation::UnicodeSegmentation;
+
+// Crate‑internal (local modules)
+use crate::char::MAQQEPH;
+use crate::{normalize, Error, HebrewAccent, Reference};
+
 /// Sentence including the context
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SentenceContext {
     /// The sentence
     pub sentence: String,
@@ -13,6 +21,7 @@ pub struct SentenceContext {
 /// Describes the context of a sentence (poetic or prosaic)
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
 #[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Context {
     /// The sentence follows a poetic structure (e.g., meter, rhyme).
     Poetic,
@@ -24,6 +33,9 @@ pub enum Context {
 impl SentenceContext {
     /// Creates a new object: SentenceContext
     ///
+    /// The sentence is kept as it is, see [`SentenceContext::new_normalized`]
+    /// to reorder its marks.
+    ///
     /// # Example
     /// ```
     /// use hebrew_accents::Context;
@@ -39,15 +51,61 @@ impl SentenceContext {
             ctx,
         }
     }
+
+    /// Creates a new SentenceContext with the marks of the sentence in the
+    /// crate canonical order, see [`normalize`](crate::normalize())
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_accents::{Context, SentenceContext};
+    ///
+    /// // Yod, Patach, Dagesh and Tiphcha
+    /// let sc = SentenceContext::new_normalized("\u{05D9}\u{05B7}\u{05BC}\u{0596}", Context::Prosaic);
+    /// assert_eq!(sc.sentence, "\u{05D9}\u{05BC}\u{05B7}\u{0596}");
+    /// ```
+    pub fn new_normalized(sentence: &str, ctx: Context) -> SentenceContext {
+        SentenceContext::new(&normalize(sentence), ctx)
+    }
+
+    /// Creates a new SentenceContext for the verse, with the context of the
+    /// book (see [`Reference::context`])
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_accents::{Context, Reference, SentenceContext};
+    ///
+    /// let reference = "Ps 23:1".parse::<Reference>().unwrap();
+    /// let sc = SentenceContext::for_reference("מִזְמ֥וֹר לְדָוִ֑ד יְהֹוָ֥ה רֹ֝עִ֗י לֹ֣א אֶחְסָֽר׃", reference);
+    /// assert_eq!(sc.ctx, Context::Poetic);
+    /// ```
+    pub fn for_reference(sentence: &str, reference: Reference) -> SentenceContext {
+        SentenceContext::new(sentence, reference.context())
+    }
+
+    /// Checks whether the sentence can be searched for the accent
+    ///
+    /// Prose accents only apply to a prosaic context and poetry accents only
+    /// to a poetic context; pseudo accents apply to any context.
+    pub(crate) fn check_accent(&self, accent: HebrewAccent) -> Result<(), Error> {
+        if self.sentence.trim().is_empty() {
+            return Err(Error::InvalidInput("the sentence is empty".to_string()));
+        }
+        match (accent, self.ctx) {
+            (HebrewAccent::Prose(_), Context::Prosaic)
+            | (HebrewAccent::Poetry(_), Context::Poetic)
+            | (HebrewAccent::Pseudo(_), _) => Ok(()),
+            (accent, ctx) => Err(Error::ContextMismatch { accent, ctx }),
+        }
+    }
 }
 
-/// Returns true if the accent is present in the sentence
-/// taking into account the context of the sentence
 /// Represents a single match if the accent is found
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Match<'h> {
-    /// The matched HebrewAccent
+    /// The sentence in which the accent is found
     pub haystack: &'h str,
+    /// The matched HebrewAccent
+    pub accent: HebrewAccent,
     /// Start byte of the match
     pub start: usize,
     /// End byte of the match
@@ -114,33 +172,90 @@ impl<'h> Match<'h> {
         self.start..self.end
     }
 
+    /// Returns the range over the starting and ending char offsets of the
+    /// match in the haystack.
+    ///
+    /// Unlike [`Match::range`], the offsets count Unicode scalar values
+    /// (`char`s) instead of bytes.
+    pub fn char_range(&self) -> core::ops::Range<usize> {
+        let start = self.haystack[..self.start].chars().count();
+        start..start + self.as_str().chars().count()
+    }
+
+    /// Returns the range over the extended grapheme clusters of the haystack
+    /// that contain the match.
+    ///
+    /// A Hebrew grapheme cluster is a consonant including all its points and
+    /// accents, so the range starts at the consonant carrying the (first)
+    /// accent and ends after the consonant carrying the last code-point.
+    pub fn grapheme_range(&self) -> core::ops::Range<usize> {
+        let mut first = 0;
+        let mut last = 0;
+        for (index, (offset, _)) in self.haystack.grapheme_indices(true).enumerate() {
+            if offset <= self.start {
+                first = index;
+            }
+            if offset < self.end {
+                last = index + 1;
+            }
+        }
+        first..last
+    }
+
     /// Returns the substring of the haystack that matched.
     #[inline]
     pub fn as_str(&self) -> &'h str {
         &self.haystack[self.range()]
     }
 
-    /// Creates a new match from the given haystack and byte offsets.
+    /// Returns the HebrewAccent that matched.
+    #[inline]
+    pub fn accent(&self) -> HebrewAccent {
+        self.accent
+    }
+
+    /// Returns the range over the starting and ending byte offsets of the
+    /// word(s) containing the match.
+    ///
+    /// A word is delimited by white space or a Maqqeph. An accent consisting
+    /// of two code-points may stretch over two words; the range then covers both.
+    /// The words of a Maqqeph are the two words it connects.
+    pub fn word_range(&self) -> core::ops::Range<usize> {
+        let is_boundary = |c: char| c.is_whitespace() || c == MAQQEPH;
+        let word_start = self.haystack[..self.start]
+            .rfind(is_boundary)
+            .map_or(0, |index| {
+                index + self.haystack[index..].chars().next().unwrap().len_utf8()
+            });
+        let word_end = self.haystack[self.end..]
+            .find(is_boundary)
+            .map_or(self.haystack.len(), |index| self.end + index);
+        word_start..word_end
+    }
+
+    /// Returns the word(s) of the haystack containing the match.
     #[inline]
-    pub(crate) fn new(haystack: &'h str, start: usize, end: usize) -> Match<'h> {
+    pub fn word(&self) -> &'h str {
+        &self.haystack[self.word_range()]
+    }
+
+    /// Creates a new match from the given haystack, accent and byte offsets.
+    #[inline]
+    pub(crate) fn new(
+        haystack: &'h str,
+        accent: HebrewAccent,
+        start: usize,
+        end: usize,
+    ) -> Match<'h> {
         Match {
             haystack,
+            accent,
             start,
             end,
         }
     }
 }
 
-// impl<'h> core::fmt::Debug for Match<'h> {
-//     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-//         f.debug_struct("Match")
-//             .field("start", &self.start)
-//             .field("end", &self.end)
-//             .field("string", &self.as_str())
-//             .finish()
-//     }
-// }
-
 impl<'h> From<Match<'h>> for &'h str {
     fn from(m: Match<'h>) -> &'h str {
         m.as_str()
@@ -152,22 +267,3 @@ impl<'h> From<Match<'h>> for core::ops::Range<usize> {
         m.range()
     }
 }
-
-// TODO add description
-// fn option_index: Option<usize>) -> Option<Match<'static>> {
-//     if let Some(index) = option_index {
-//         // all single characters accents consists two bytes, so
-//         // the next character will be located at index+2'
-//         Some(Match::new(
-//             "TODO: insert single character",
-//             index,
-//             index + 2,
-//         ))
-//     } else {
-//         None
-//     }
-//}
-
-// fn option_index: Option<usize>) -> Option<Match<'static>> {
-//     option_index.map(|index| Match::new("TODO: insert single character", index, index + 2))
-// }
@@ -0,0 +1,104 @@
+// Standard library
+use std::collections::HashSet;
+
+// External crates
+
+// Local modules / crate‑internal
+use crate::sentence_ctx_matcher::{code_point_offsets, matcher, Matcher};
+use crate::trace::trace_event;
+use crate::{
+    Accent, Context, Error, HebrewAccent, Match, PoetryAccent, ProseAccent, PseudoAccent,
+    SentenceContext,
+};
+
+impl SentenceContext {
+    /// Classifies every accent mark in the sentence, taking into account the context.
+    ///
+    /// Returns the accents in the order in which they occur in the sentence.
+    /// Each code-point is assigned to exactly one accent, e.g. a Munach followed
+    /// by a Paseq is a Legarmeh (and not a Munach), a Tiphcha in the same word
+    /// as the Atnach is a Mayela (and not a Tiphcha).
+    ///
+    /// The accents are found with the same rules as `find_iter()`, so every
+    /// [`Match`] of the analysis is also returned by `find_iter()`. If two
+    /// accents claim the same code-point, the accent consisting of two
+    /// code-points wins (e.g. a Legarmeh over a Paseq), then the accent found
+    /// by a rule of the context over the bare mark (e.g. a Mayela over a Tiphcha).
+    /// Marks that do not belong to any accent of the context are skipped.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_accents::{Context, HebrewAccent, ProseAccent, SentenceContext};
+    ///
+    /// let sc = SentenceContext::new("בּראשׁית בּ֣רא א֣להים׀ את", Context::Prosaic);
+    /// let accents: Vec<HebrewAccent> = sc.analyze().into_iter().map(|(a, _)| a).collect();
+    /// assert_eq!(
+    ///     accents,
+    ///     vec![ProseAccent::Munach.into(), ProseAccent::Legarmeh.into()]
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the backtrack limit of a regular expression is exceeded,
+    /// use [`SentenceContext::try_analyze`] to handle this case.
+    pub fn analyze(&self) -> Vec<(HebrewAccent, Match<'_>)> {
+        self.try_analyze()
+            .expect("analyzing the accents of the sentence failed")
+    }
+
+    /// Classifies every accent mark in the sentence, like
+    /// [`SentenceContext::analyze`], but returns an error instead of panicking.
+    ///
+    /// # Errors
+    /// - [`Error::BacktrackLimitExceeded`] if the backtrack limit of a regular expression is exceeded
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_accents::{Context, ProseAccent, SentenceContext};
+    ///
+    /// let sc = SentenceContext::new("בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים", Context::Prosaic);
+    /// let analysis = sc.try_analyze().unwrap();
+    /// assert_eq!(analysis[2].0, ProseAccent::Atnach.into());
+    /// ```
+    pub fn try_analyze(&self) -> Result<Vec<(HebrewAccent, Match<'_>)>, Error> {
+        let accents: Vec<HebrewAccent> = match self.ctx {
+            Context::Prosaic => ProseAccent::ALL.iter().map(|&a| a.into()).collect(),
+            Context::Poetic => PoetryAccent::ALL.iter().map(|&a| a.into()).collect(),
+        };
+        let mut candidates = Vec::new();
+        for accent in accents
+            .into_iter()
+            .chain(PseudoAccent::ALL.iter().map(|&a| a.into()))
+        {
+            if let Some(matcher) = matcher(accent, self.ctx) {
+                let bare_mark = matches!(matcher, Matcher::Char(_));
+                for found in matcher.find_all(&self.sentence, accent)? {
+                    candidates.push((bare_mark, found));
+                }
+            }
+        }
+        // accents of two code-points first, then the accents found by a rule
+        // before the bare marks, the order of the table otherwise
+        candidates.sort_by_key(|(bare_mark, found)| {
+            (std::cmp::Reverse(found.accent.code_points()), *bare_mark)
+        });
+        let mut claimed = HashSet::new();
+        let mut analysis = Vec::new();
+        for (_, found) in candidates {
+            let offsets = code_point_offsets(&found);
+            if offsets.iter().any(|offset| claimed.contains(offset)) {
+                trace_event!(
+                    scanner = "analyze",
+                    accent = ?found.accent,
+                    start = found.start,
+                    "code-point already claimed"
+                );
+                continue;
+            }
+            claimed.extend(offsets);
+            analysis.push((found.accent, found));
+        }
+        analysis.sort_by_key(|(_, found)| (found.start, found.end));
+        Ok(analysis)
+    }
+}
@@ -0,0 +1,130 @@
+// Standard library
+use std::io;
+
+// External crates
+
+// Local modules / crate‑internal
+use crate::ansi::{paint, sgr};
+use crate::{ansi_legend, AnsiScheme, Error, HebrewAccent, SentenceContext, Word};
+
+impl SentenceContext {
+    /// Renders the sentence for a terminal, coloring every word that carries
+    /// an accent, taking into account the context.
+    ///
+    /// A word with more than one accent is colored by its principal accent
+    /// (see [`Word::principal_accent`](crate::Word::principal_accent)).
+    /// Without color the sentence is returned unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_accents::{AnsiScheme, Context, SentenceContext};
+    ///
+    /// let sc = SentenceContext::new("בָּרָ֣א אֱלֹהִ֑ים", Context::Prosaic);
+    /// assert_eq!(
+    ///     sc.to_ansi(AnsiScheme::Category, true),
+    ///     "\x1b[34mבָּרָ֣א\x1b[0m \x1b[31mאֱלֹהִ֑ים\x1b[0m"
+    /// );
+    /// assert_eq!(sc.to_ansi(AnsiScheme::Category, false), sc.sentence);
+    /// ```
+    pub fn to_ansi(&self, scheme: AnsiScheme, color: bool) -> String {
+        paint_words(self, &self.words(), scheme, color, |word| {
+            word.principal_accent()
+        })
+    }
+
+    /// Renders the sentence for a terminal, coloring only the words that
+    /// carry the accent, taking into account the context.
+    ///
+    /// Without color the sentence is returned unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_accents::{AnsiScheme, Context, ProseAccent, SentenceContext};
+    ///
+    /// let sc = SentenceContext::new("בָּרָ֣א אֱלֹהִ֑ים", Context::Prosaic);
+    /// assert_eq!(
+    ///     sc.to_ansi_accent(ProseAccent::Atnach.into(), AnsiScheme::Category, true),
+    ///     "בָּרָ֣א \x1b[31mאֱלֹהִ֑ים\x1b[0m"
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the backtrack limit of a regular expression is exceeded,
+    /// use [`SentenceContext::try_to_ansi_accent`] to handle this case.
+    pub fn to_ansi_accent(&self, accent: HebrewAccent, scheme: AnsiScheme, color: bool) -> String {
+        self.try_to_ansi_accent(accent, scheme, color)
+            .expect("analyzing the accents of the sentence failed")
+    }
+
+    /// Renders the sentence for a terminal, coloring only the words that
+    /// carry the accent, like [`SentenceContext::to_ansi_accent`], but returns
+    /// an error instead of panicking.
+    ///
+    /// # Errors
+    /// - [`Error::BacktrackLimitExceeded`] if the backtrack limit of a regular expression is exceeded
+    pub fn try_to_ansi_accent(
+        &self,
+        accent: HebrewAccent,
+        scheme: AnsiScheme,
+        color: bool,
+    ) -> Result<String, Error> {
+        let words = self.try_words()?;
+        Ok(paint_words(self, &words, scheme, color, |word| {
+            word.accents().contains(&accent).then_some(accent)
+        }))
+    }
+
+    /// Writes the sentence and the legend of the scheme to the writer
+    ///
+    /// Use [`use_color`](crate::use_color) to decide on color for stdout.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_accents::{AnsiScheme, Context, SentenceContext};
+    ///
+    /// let sc = SentenceContext::new("בָּרָ֣א אֱלֹהִ֑ים", Context::Prosaic);
+    /// let mut out = Vec::new();
+    /// sc.write_ansi(&mut out, AnsiScheme::Category, false).unwrap();
+    /// let out = String::from_utf8(out).unwrap();
+    /// assert!(out.starts_with("בָּרָ֣א אֱלֹהִ֑ים\ndisjunctive: Silluq"));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns the I/O error of the writer.
+    pub fn write_ansi<W: io::Write>(
+        &self,
+        writer: &mut W,
+        scheme: AnsiScheme,
+        color: bool,
+    ) -> io::Result<()> {
+        writeln!(writer, "{}", self.to_ansi(scheme, color))?;
+        write!(writer, "{}", ansi_legend(scheme, self.ctx, color))
+    }
+}
+
+/* helper functions */
+
+/// Paints every word for which `pick` returns an accent in the color of that accent
+fn paint_words<F>(
+    sc: &SentenceContext,
+    words: &[Word<'_>],
+    scheme: AnsiScheme,
+    color: bool,
+    pick: F,
+) -> String
+where
+    F: Fn(&Word<'_>) -> Option<HebrewAccent>,
+{
+    let mut text = String::new();
+    let mut pos = 0;
+    for word in words {
+        text.push_str(&sc.sentence[pos..word.start]);
+        match pick(word) {
+            Some(accent) => text.push_str(&paint(word.as_str(), sgr(scheme, accent), color)),
+            None => text.push_str(word.as_str()),
+        }
+        pos = word.end;
+    }
+    text.push_str(&sc.sentence[pos..]);
+    text
+}
@@ -0,0 +1,69 @@
+// Standard library
+
+// External crates
+
+// Local modules / crate‑internal
+use crate::sentence_ctx_matcher::matcher;
+use crate::{Error, HebrewAccent, Match, SentenceContext};
+
+impl SentenceContext {
+    /// Returns an iterator over all non-overlapping matches of a HebrewAccent
+    /// in the sentence, taking into account the context.
+    ///
+    /// The matches are yielded in the order in which they occur in the sentence.
+    /// The first item is the same [`Match`] as returned by `find_accent()`.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_accents::{Context, ProseAccent, SentenceContext};
+    ///
+    /// let sc = SentenceContext::new("ויּ֣ר֖א עשׂ֔ו כּ֥י רע֖ות נ֣ות כּ֖נ֑ען בּעינ֖י יצח֥ק א֖בֽיו׃", Context::Prosaic);
+    /// assert_eq!(sc.find_iter(ProseAccent::Tiphcha.into()).count(), 5);
+    /// assert_eq!(sc.find_iter(ProseAccent::Mayela.into()).count(), 2);
+    /// assert_eq!(sc.find_iter(ProseAccent::Segolta.into()).count(), 0);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the backtrack limit of a regular expression is exceeded,
+    /// use [`SentenceContext::try_find_iter`] to handle this case.
+    pub fn find_iter(&self, accent: HebrewAccent) -> impl Iterator<Item = Match<'_>> {
+        self.search_all(accent)
+            .expect("searching the sentence for the accent failed")
+            .into_iter()
+    }
+
+    /// Returns an iterator over all non-overlapping matches of a HebrewAccent
+    /// in the sentence, like [`SentenceContext::find_iter`], but returns an
+    /// error instead of panicking.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidInput`] if the sentence is empty
+    /// - [`Error::ContextMismatch`] if the accent does not apply to the context
+    /// - [`Error::BacktrackLimitExceeded`] if the backtrack limit of a regular expression is exceeded
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_accents::{Context, Error, PoetryAccent, ProseAccent, SentenceContext};
+    ///
+    /// let sc = SentenceContext::new("ויּ֣ר֖א עשׂ֔ו כּ֥י רע֖ות נ֣ות כּ֖נ֑ען בּעינ֖י יצח֥ק א֖בֽיו׃", Context::Prosaic);
+    /// assert_eq!(sc.try_find_iter(ProseAccent::Munach.into()).unwrap().count(), 2);
+    /// assert!(matches!(
+    ///     sc.try_find_iter(PoetryAccent::Dechi.into()),
+    ///     Err(Error::ContextMismatch { .. })
+    /// ));
+    /// ```
+    pub fn try_find_iter(
+        &self,
+        accent: HebrewAccent,
+    ) -> Result<impl Iterator<Item = Match<'_>>, Error> {
+        self.check_accent(accent)?;
+        Ok(self.search_all(accent)?.into_iter())
+    }
+
+    fn search_all(&self, accent: HebrewAccent) -> Result<Vec<Match<'_>>, Error> {
+        match matcher(accent, self.ctx) {
+            Some(matcher) => matcher.find_all(&self.sentence, accent),
+            None => Ok(Vec::new()),
+        }
+    }
+}
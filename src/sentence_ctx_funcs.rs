@@ -6,11 +6,13 @@
 
 // Crate‑internal (local modules)
 use crate::char::{
-    GERESH, MAHPAKH, MAQQEPH, MERKHA, OLEH, PASEQ, REVIA, TSINNORIT, VERTICAL_LINE, YORED, ZARQA,
+    GERESH, GERESH_MUQDAM, MAHPAKH, MAQQEPH, MERKHA, OLEH, PASEQ, REVIA, TSINNORIT, VERTICAL_LINE,
+    YORED, ZARQA,
 };
-use crate::Match;
+use crate::trace::trace_event;
+use crate::{Match, PoetryAccent};
 
-pub(crate) fn find_poetry_merkha(sentence: &str) -> Option<Match<'static>> {
+pub(crate) fn find_all_poetry_merkha(sentence: &str) -> Vec<Match<'_>> {
     // Merkha (as a poetry accent) is
     //   not part of Oleh We Yored (needs Negative Lookbehind)
     //   AND
@@ -21,12 +23,13 @@ pub(crate) fn find_poetry_merkha(sentence: &str) -> Option<Match<'static>> {
 
     // Check for the existence of the target character in the sentence
     if !&sentence.contains(target_char) {
-        return None;
+        return Vec::new();
     }
-    // Convert the sentence into a Vec<char> for character indexing
-    let char_vec: Vec<char> = as_char_slice(sentence);
+    // Convert the sentence into a Vec<char> for character indexing, keeping the byte offsets
+    let (byte_offsets, char_vec): (Vec<usize>, Vec<char>) = sentence.char_indices().unzip();
     // Find the indices of the target character within the sentence
     let indices = indexes_target_char(target_char, &char_vec);
+    let mut matches = Vec::new();
     // loop over all character positions
     for &index in &indices {
         let is_part_of = is_part_of_two_code_point_accent_look_behind(
@@ -36,18 +39,25 @@ pub(crate) fn find_poetry_merkha(sentence: &str) -> Option<Match<'static>> {
             &possible_combinations_lookbehind,
             2,
         );
+        trace_event!(
+            scanner = "find_all_poetry_merkha",
+            index,
+            is_part_of,
+            "Merkha candidate checked"
+        );
         if !is_part_of {
-            // println!(
-            //     "Found at least one target char, not part of another aaccent:: BREAK the loop"
-            // );
-            let merkha = "\u{05A5}";
-            return Some(Match::new(merkha, index, index + MERKHA.len_utf8()));
+            matches.push(Match::new(
+                sentence,
+                PoetryAccent::Merkha.into(),
+                byte_offsets[index],
+                byte_offsets[index] + MERKHA.len_utf8(),
+            ));
         }
     }
-    None
+    matches
 }
 
-pub(crate) fn find_poetry_mehuppakh(sentence: &str) -> Option<Match<'static>> {
+pub(crate) fn find_all_poetry_mehuppakh(sentence: &str) -> Vec<Match<'_>> {
     // Mehupppakh (as a poetry accent)
     //   not part of Mehuppakh Legarmeh (needs Negative Lookahead)
     //   AND
@@ -57,18 +67,15 @@ pub(crate) fn find_poetry_mehuppakh(sentence: &str) -> Option<Match<'static>> {
     let possible_combinations_lookbehind = [ZARQA];
     // check if the target character is present in the sentence
     if !&sentence.contains(target_char) {
-        //println!("MEHUPPAKH not found in the senctence at all  -> return None");
-        return None;
+        return Vec::new();
     }
-    // turn sentence into a Vec of chars for indexing
-    let char_vec: Vec<char> = as_char_slice(sentence);
+    // turn sentence into a Vec of chars for indexing, keeping the byte offsets
+    let (byte_offsets, char_vec): (Vec<usize>, Vec<char>) = sentence.char_indices().unzip();
     // retrieve character positions of the target character
     let indices: Vec<usize> = indexes_target_char(target_char, &char_vec);
-    println!("positions found at: {indices:?}");
+    let mut matches = Vec::new();
     // loop over all character positions
     for index in indices {
-        println!("\n\nCHECK position {index}");
-        //println!("\nNegative Looking Backward");
         let two_code_points_behind = is_part_of_two_code_point_accent_look_behind(
             &char_vec,
             target_char,
@@ -77,36 +84,42 @@ pub(crate) fn find_poetry_mehuppakh(sentence: &str) -> Option<Match<'static>> {
             2,
         );
         let is_part_of_mahpakh_legarmeh = is_part_of_mahpakh_legarmeh_look_ahead(index, &char_vec);
-        // println!("\nResult: Negative Looking Backward = {two_code_points_behind}");
-        // println!("Result: Negative Looking Forward = {is_part_of_mahpakh_legarmeh}");
+        trace_event!(
+            scanner = "find_all_poetry_mehuppakh",
+            index,
+            two_code_points_behind,
+            is_part_of_mahpakh_legarmeh,
+            "Mehuppakh candidate checked"
+        );
         if !two_code_points_behind && !is_part_of_mahpakh_legarmeh {
-            // println!(
-            //     "\nResult for index {index}:\n\ttwo_code_points_behind: {two_code_points_behind}\n\tis_part_of_mahpakh_legarmeh: {is_part_of_mahpakh_legarmeh}"
-            // );
-            // println!("Found target char, not part of another accent. Returning TRUE");
-            let mahpakh = "\u{05A4}";
-            return Some(Match::new(mahpakh, index, index + MAHPAKH.len_utf8()));
+            matches.push(Match::new(
+                sentence,
+                PoetryAccent::Mehuppakh.into(),
+                byte_offsets[index],
+                byte_offsets[index] + MAHPAKH.len_utf8(),
+            ));
         }
     }
-    None
+    matches
 }
 
-pub(crate) fn find_poetry_revia_gadol(sentence: &str) -> Option<Match<'static>> {
+pub(crate) fn find_all_poetry_revia_gadol(sentence: &str) -> Vec<Match<'_>> {
     // Revia Gadol is
     //   not part of Revia Mugrash (needs Negative Lookbehind)
     //   AND
     //   not followed by an Oleh We Yored (needs Negative Lookahead)
     let target_char = REVIA;
     // define possible combinations
-    let possible_combinations_lookbehind = [GERESH];
+    let possible_combinations_lookbehind = [GERESH, GERESH_MUQDAM];
     // check if the target character is present in the senctence
     if !&sentence.contains(target_char) {
-        return None;
+        return Vec::new();
     }
-    // turn sentence into a Vec of chars for indexing
-    let char_vec: Vec<char> = as_char_slice(sentence);
+    // turn sentence into a Vec of chars for indexing, keeping the byte offsets
+    let (byte_offsets, char_vec): (Vec<usize>, Vec<char>) = sentence.char_indices().unzip();
     // retrieve character positions of the target character
     let indices: Vec<usize> = indexes_target_char(target_char, &char_vec);
+    let mut matches = Vec::new();
     // loop over all character positions
     for index in indices {
         let two_code_points_behind = is_part_of_two_code_point_accent_look_behind(
@@ -122,38 +135,44 @@ pub(crate) fn find_poetry_revia_gadol(sentence: &str) -> Option<Match<'static>>
         //  no      yes     -       no
         //  yes     no      -       no
         //  yes     yes     -       no
-        // println!(
-        //     "two_code_points_behind: {two_code_points_behind} - followed_by_owy: {followed_by_owy}"
-        // );
+        trace_event!(
+            scanner = "find_all_poetry_revia_gadol",
+            index,
+            two_code_points_behind,
+            followed_by_owy,
+            "Revia Gadol candidate checked"
+        );
         if !two_code_points_behind && !followed_by_owy {
-            let revia = "\u{0597}";
-            return Some(Match::new(revia, index, index + REVIA.len_utf8()));
+            matches.push(Match::new(
+                sentence,
+                PoetryAccent::ReviaGadol.into(),
+                byte_offsets[index],
+                byte_offsets[index] + REVIA.len_utf8(),
+            ));
         }
     }
-    None
+    matches
 }
 
-pub fn find_poetry_revia_qaton(sentence: &str) -> Option<Match<'static>> {
+pub(crate) fn find_all_poetry_revia_qaton(sentence: &str) -> Vec<Match<'_>> {
     // Revia Qaton is
     //   not part of Revia Mugrash (needs Negative Lookbehind)
     //   AND
     //   followed by an Oleh We Yored (needs Positive LookAhead)
     let target_char = REVIA;
     // define possible combinations
-    let possible_combinations_lookbehind = [GERESH];
+    let possible_combinations_lookbehind = [GERESH, GERESH_MUQDAM];
     // check if the target character is present in the senctence
     if !&sentence.contains(target_char) {
-        println!("REVIA not found in the senctence at all  -> return None");
-        return None;
+        return Vec::new();
     }
-    // turn sentence into a Vec of chars for indexing
-    let char_vec: Vec<char> = as_char_slice(sentence);
+    // turn sentence into a Vec of chars for indexing, keeping the byte offsets
+    let (byte_offsets, char_vec): (Vec<usize>, Vec<char>) = sentence.char_indices().unzip();
     // retrieve character positions of the target character
     let indices: Vec<usize> = indexes_target_char(target_char, &char_vec);
+    let mut matches = Vec::new();
     // loop over all character positions
     for index in indices {
-        // println!("\n\nLOOP::Index of target character = {index}\n");
-        // println!("Negative Looking Backward");
         let two_code_points_behind = is_part_of_two_code_point_accent_look_behind(
             &char_vec,
             target_char,
@@ -161,32 +180,35 @@ pub fn find_poetry_revia_qaton(sentence: &str) -> Option<Match<'static>> {
             &possible_combinations_lookbehind,
             1,
         );
-        //println!("Followed by Oleh We Yored");
         let followed_by_owy = is_followed_by_oleh_we_yored(index, &char_vec);
         // 2cp   oleweyored     revia_qaton
         //  no      no      -       no
         //  no      yes     -       yes
         //  yes     no      -       no
         //  yes     yes     -       no
-        // println!(
-        //     "two_code_points_behind:{two_code_points_behind} - followed_by_owy{followed_by_owy}"
-        // );
+        trace_event!(
+            scanner = "find_all_poetry_revia_qaton",
+            index,
+            two_code_points_behind,
+            followed_by_owy,
+            "Revia Qaton candidate checked"
+        );
         if !two_code_points_behind && followed_by_owy {
-            let revia = "\u{0597}";
-            return Some(Match::new(revia, index, index + REVIA.len_utf8()));
+            matches.push(Match::new(
+                sentence,
+                PoetryAccent::ReviaQaton.into(),
+                byte_offsets[index],
+                byte_offsets[index] + REVIA.len_utf8(),
+            ));
         }
     }
-    None
+    matches
 }
 
 /*
 helper functions
 */
 
-fn as_char_slice(s: &str) -> Vec<char> {
-    s.chars().collect()
-}
-
 fn indexes_target_char(target_char: char, sentence: &[char]) -> Vec<usize> {
     sentence
         .iter()
@@ -273,7 +295,7 @@ fn is_part_of_mahpakh_legarmeh_look_ahead(idx_target: usize, sentence: &[char])
     false
 }
 
-fn is_followed_by_oleh_we_yored(target_idx: usize, sentence: &[char]) -> bool {
+pub(crate) fn is_followed_by_oleh_we_yored(target_idx: usize, sentence: &[char]) -> bool {
     // Guard against an out‑of‑range index.
     if target_idx >= sentence.len() {
         return false;
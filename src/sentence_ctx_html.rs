@@ -0,0 +1,101 @@
+// Standard library
+
+// External crates
+use unicode_segmentation::UnicodeSegmentation;
+
+// Local modules / crate‑internal
+use crate::html::{escape, open_span};
+use crate::{HebrewAccent, HtmlSpans, Naming, SentenceContext};
+
+impl SentenceContext {
+    /// Renders the sentence as an HTML paragraph, wrapping every classified
+    /// accent in a `<span>`, taking into account the context.
+    ///
+    /// The paragraph has `dir="rtl"`, `lang="he"` and the class `hebrew-accents`.
+    /// Every span has the classes `accent` and the category (`disjunctive`,
+    /// `conjunctive` or `pseudo`), the data attributes `data-accent`,
+    /// `data-category` and `data-strength` and a tooltip (`title`) with the
+    /// meaning of the accent. The names are the BHS names, see
+    /// [`SentenceContext::to_html_in`] for other names. All text is escaped.
+    ///
+    /// See [`DEFAULT_STYLESHEET`](crate::DEFAULT_STYLESHEET) for a stylesheet.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_accents::{Context, HtmlSpans, SentenceContext};
+    ///
+    /// let sc = SentenceContext::new("בָּרָ֣א אֱלֹהִ֑ים", Context::Prosaic);
+    /// let html = sc.to_html(HtmlSpans::Word);
+    /// assert!(html.starts_with("<p dir=\"rtl\" lang=\"he\" class=\"hebrew-accents\">"));
+    /// assert!(html.contains("data-accent=\"Atnach\" data-category=\"disjunctive\" data-strength=\"2\""));
+    /// assert!(html.contains("title=\"Atnach: a causing to rest\">אֱלֹהִ֑ים</span>"));
+    /// ```
+    pub fn to_html(&self, spans: HtmlSpans) -> String {
+        self.to_html_in(spans, Naming::Bhs)
+    }
+
+    /// Renders the sentence as an HTML paragraph, like [`SentenceContext::to_html`],
+    /// with the names of the accents in the naming
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_accents::{Context, HtmlSpans, Naming, SentenceContext, TraditionKind};
+    ///
+    /// let sc = SentenceContext::new("בָּרָ֣א אֱלֹהִ֑ים", Context::Prosaic);
+    /// let html = sc.to_html_in(HtmlSpans::Word, Naming::Tradition(TraditionKind::Ashkenazi));
+    /// assert!(html.contains("data-accent=\"Etnachta\""));
+    /// ```
+    pub fn to_html_in(&self, spans: HtmlSpans, naming: Naming) -> String {
+        let body = match spans {
+            HtmlSpans::Word => self.html_words(naming),
+            HtmlSpans::Mark => self.html_marks(naming),
+        };
+        format!(
+            "<p dir=\"rtl\" lang=\"he\" class=\"hebrew-accents\">{}</p>",
+            body
+        )
+    }
+
+    /// Wraps every word with an accent
+    fn html_words(&self, naming: Naming) -> String {
+        let mut html = String::new();
+        let mut pos = 0;
+        for word in self.words() {
+            html.push_str(&escape(&self.sentence[pos..word.start]));
+            match word.principal_accent() {
+                Some(accent) => {
+                    html.push_str(&open_span(accent, naming));
+                    html.push_str(&escape(word.as_str()));
+                    html.push_str("</span>");
+                }
+                None => html.push_str(&escape(word.as_str())),
+            }
+            pos = word.end;
+        }
+        html.push_str(&escape(&self.sentence[pos..]));
+        html
+    }
+
+    /// Wraps every grapheme cluster (a consonant with its points and accents)
+    /// carrying a code-point of an accent
+    fn html_marks(&self, naming: Naming) -> String {
+        let marks = self.accent_marks();
+        let mut html = String::new();
+        for (offset, grapheme) in self.sentence.grapheme_indices(true) {
+            let mut accents: Vec<(usize, HebrewAccent)> = marks
+                .iter()
+                .filter(|(mark, _)| (offset..offset + grapheme.len()).contains(*mark))
+                .map(|(mark, accent)| (*mark, *accent))
+                .collect();
+            accents.sort();
+            for (_, accent) in &accents {
+                html.push_str(&open_span(*accent, naming));
+            }
+            html.push_str(&escape(grapheme));
+            for _ in &accents {
+                html.push_str("</span>");
+            }
+        }
+        html
+    }
+}
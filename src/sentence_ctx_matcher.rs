@@ -0,0 +1,300 @@
+//! The rules that find an accent in a sentence
+//!
+//! Every search (`contains_accent()`, `find_accent()`, `find_iter()` and
+//! `analyze()`) looks up the [`Matcher`] of the accent in one table, see
+//! [`matcher`], so the searches can not differ in what they find.
+
+// Standard library
+
+// External crates
+use fancy_regex::Regex as FancyRegex;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+// Crate‑internal (local modules)
+use crate::char::{
+    DARGA, DEHI, ETNAHTA, GERESH, GERSHAYIM, ILUY, MAHPAKH, MAQQEPH, MERKHA, MERKHA_KEFULA, MUNAH,
+    PASEQ, PASHTA, PAZER, QADMA, QARNEY_PARA, REVIA, SEGOL, SOF_PASUQ, TELISHA_GEDOLA,
+    TELISHA_QETANA, TEVIR, TIPEHA, YERAH_BEN_YOMO, YETIV, ZAQEF_GADOL, ZAQEF_QATAN, ZARQA, ZINOR,
+};
+use crate::sentence_ctx_funcs::{
+    find_all_poetry_mehuppakh, find_all_poetry_merkha, find_all_poetry_revia_gadol,
+    find_all_poetry_revia_qaton,
+};
+use crate::sentence_ctx_regex::{
+    FA_RE_OUTER_COMMON_METEG, FA_RE_OUTER_COMMON_SILLUQ, FA_RE_OUTER_POETRY_AZLA,
+    FA_RE_OUTER_POETRY_SHALSHELET_QETANNAH, FA_RE_OUTER_PROSE_MEAYLA, FA_RE_OUTER_PROSE_MUNACH,
+    RE_INNER_COMMON_SHALSHELET, RE_INNER_POETRY_TSINNORIT_MAHPAKH,
+    RE_INNER_POETRY_TSINNORIT_MERKHA, RE_INNER_PROSE_LEGARMEH, RE_OUTER_COMMON_SHALSHELET,
+    RE_OUTER_POETRY_AZLA_LEGARMEH, RE_OUTER_POETRY_MEHUPPAKH_LEGARMEH,
+    RE_OUTER_POETRY_OLEH_WE_YORED, RE_OUTER_POETRY_REVIA_MUGRASH,
+    RE_OUTER_POETRY_TSINNORIT_MAHPAKH, RE_OUTER_POETRY_TSINNORIT_MERKHA, RE_OUTER_PROSE_LEGARMEH,
+};
+use crate::trace::trace_event;
+use crate::{Accent, Context, Error, HebrewAccent, Match, PoetryAccent, ProseAccent, PseudoAccent};
+
+/// How the occurrences of an accent are found
+pub(crate) enum Matcher {
+    /// A single code-point
+    Char(char),
+    /// The matches of a regex (and its name)
+    Regex(&'static Lazy<Regex>, &'static str),
+    /// The matches of a fancy regex (and its name)
+    Fancy(&'static Lazy<FancyRegex>, &'static str),
+    /// Within every match of the outer regex, the match of the inner regex
+    /// (and the name of the inner regex)
+    OuterInner(&'static Lazy<Regex>, &'static Lazy<Regex>, &'static str),
+    /// A scanner over the characters of the sentence
+    Scanner(fn(&str) -> Vec<Match<'_>>),
+}
+
+/// Returns the matcher of the accent in the context, None if the accent can
+/// not occur in the context
+pub(crate) fn matcher(accent: HebrewAccent, ctx: Context) -> Option<Matcher> {
+    let prosaic = ctx == Context::Prosaic;
+    let poetic = ctx == Context::Poetic;
+    let matcher = match accent {
+        /* **********************************************************
+         *                          PROSE
+         * *********************************************************/
+        // Disjunctives
+        HebrewAccent::Prose(ProseAccent::Silluq) if prosaic => {
+            Matcher::Fancy(&FA_RE_OUTER_COMMON_SILLUQ, "FA_RE_OUTER_COMMON_SILLUQ")
+        }
+        HebrewAccent::Prose(ProseAccent::Atnach) if prosaic => Matcher::Char(ETNAHTA),
+        HebrewAccent::Prose(ProseAccent::Segolta) if prosaic => Matcher::Char(SEGOL),
+        HebrewAccent::Prose(ProseAccent::Shalshelet) if prosaic => Matcher::OuterInner(
+            &RE_OUTER_COMMON_SHALSHELET,
+            &RE_INNER_COMMON_SHALSHELET,
+            "RE_INNER_COMMON_SHALSHELET",
+        ),
+        HebrewAccent::Prose(ProseAccent::ZaqephQaton) if prosaic => Matcher::Char(ZAQEF_QATAN),
+        HebrewAccent::Prose(ProseAccent::ZaqephGadol) if prosaic => Matcher::Char(ZAQEF_GADOL),
+        HebrewAccent::Prose(ProseAccent::Revia) if prosaic => Matcher::Char(REVIA),
+        HebrewAccent::Prose(ProseAccent::Tiphcha) if prosaic => Matcher::Char(TIPEHA),
+        HebrewAccent::Prose(ProseAccent::Zarqa) if prosaic => Matcher::Char(ZARQA),
+        HebrewAccent::Prose(ProseAccent::Pashta) if prosaic => Matcher::Char(PASHTA),
+        HebrewAccent::Prose(ProseAccent::Yetiv) if prosaic => Matcher::Char(YETIV),
+        HebrewAccent::Prose(ProseAccent::Tevir) if prosaic => Matcher::Char(TEVIR),
+        HebrewAccent::Prose(ProseAccent::Geresh) if prosaic => Matcher::Char(GERESH),
+        HebrewAccent::Prose(ProseAccent::Gershayim) if prosaic => Matcher::Char(GERSHAYIM),
+        HebrewAccent::Prose(ProseAccent::Pazer) if prosaic => Matcher::Char(PAZER),
+        HebrewAccent::Prose(ProseAccent::PazerGadol) if prosaic => Matcher::Char(QARNEY_PARA),
+        HebrewAccent::Prose(ProseAccent::TelishaGedolah) if prosaic => {
+            Matcher::Char(TELISHA_GEDOLA)
+        }
+        HebrewAccent::Prose(ProseAccent::Legarmeh) if prosaic => Matcher::OuterInner(
+            &RE_OUTER_PROSE_LEGARMEH,
+            &RE_INNER_PROSE_LEGARMEH,
+            "RE_INNER_PROSE_LEGARMEH",
+        ),
+        // Conjunctives
+        HebrewAccent::Prose(ProseAccent::Munach) if prosaic => {
+            Matcher::Fancy(&FA_RE_OUTER_PROSE_MUNACH, "FA_RE_OUTER_PROSE_MUNACH")
+        }
+        HebrewAccent::Prose(ProseAccent::Mahpakh) if prosaic => Matcher::Char(MAHPAKH),
+        HebrewAccent::Prose(ProseAccent::Merkha) if prosaic => Matcher::Char(MERKHA),
+        HebrewAccent::Prose(ProseAccent::MerkhaKephulah) if prosaic => Matcher::Char(MERKHA_KEFULA),
+        HebrewAccent::Prose(ProseAccent::Darga) if prosaic => Matcher::Char(DARGA),
+        HebrewAccent::Prose(ProseAccent::Azla) if prosaic => Matcher::Char(QADMA),
+        HebrewAccent::Prose(ProseAccent::TelishaQetannah) if prosaic => {
+            Matcher::Char(TELISHA_QETANA)
+        }
+        HebrewAccent::Prose(ProseAccent::Galgal) if prosaic => Matcher::Char(YERAH_BEN_YOMO),
+        HebrewAccent::Prose(ProseAccent::Mayela) if prosaic => {
+            Matcher::Fancy(&FA_RE_OUTER_PROSE_MEAYLA, "FA_RE_OUTER_PROSE_MEAYLA")
+        }
+        HebrewAccent::Prose(ProseAccent::Meteg) if prosaic => {
+            Matcher::Fancy(&FA_RE_OUTER_COMMON_METEG, "FA_RE_OUTER_COMMON_METEG")
+        }
+        /* **********************************************************
+         *                          PSEUDO
+         * *********************************************************/
+        HebrewAccent::Pseudo(PseudoAccent::SophPasuq) => Matcher::Char(SOF_PASUQ),
+        HebrewAccent::Pseudo(PseudoAccent::Maqqeph) => Matcher::Char(MAQQEPH),
+        HebrewAccent::Pseudo(PseudoAccent::Paseq) => Matcher::Char(PASEQ),
+        /* **********************************************************
+         *                          POETRY
+         * *********************************************************/
+        // Disjunctives
+        HebrewAccent::Poetry(PoetryAccent::Silluq) if poetic => {
+            Matcher::Fancy(&FA_RE_OUTER_COMMON_SILLUQ, "FA_RE_OUTER_COMMON_SILLUQ")
+        }
+        HebrewAccent::Poetry(PoetryAccent::Atnach) if poetic => Matcher::Char(ETNAHTA),
+        HebrewAccent::Poetry(PoetryAccent::OlehWeYored) if poetic => Matcher::Regex(
+            &RE_OUTER_POETRY_OLEH_WE_YORED,
+            "RE_OUTER_POETRY_OLEH_WE_YORED",
+        ),
+        HebrewAccent::Poetry(PoetryAccent::ReviaGadol) if poetic => {
+            Matcher::Scanner(find_all_poetry_revia_gadol)
+        }
+        HebrewAccent::Poetry(PoetryAccent::ReviaMugrash) if poetic => Matcher::Regex(
+            &RE_OUTER_POETRY_REVIA_MUGRASH,
+            "RE_OUTER_POETRY_REVIA_MUGRASH",
+        ),
+        HebrewAccent::Poetry(PoetryAccent::ShalsheletGadol) if poetic => Matcher::OuterInner(
+            &RE_OUTER_COMMON_SHALSHELET,
+            &RE_INNER_COMMON_SHALSHELET,
+            "RE_INNER_COMMON_SHALSHELET",
+        ),
+        HebrewAccent::Poetry(PoetryAccent::Tsinnor) if poetic => Matcher::Char(ZINOR),
+        HebrewAccent::Poetry(PoetryAccent::ReviaQaton) if poetic => {
+            Matcher::Scanner(find_all_poetry_revia_qaton)
+        }
+        HebrewAccent::Poetry(PoetryAccent::Dechi) if poetic => Matcher::Char(DEHI),
+        HebrewAccent::Poetry(PoetryAccent::MehuppakhLegarmeh) if poetic => Matcher::Regex(
+            &RE_OUTER_POETRY_MEHUPPAKH_LEGARMEH,
+            "RE_OUTER_POETRY_MEHUPPAKH_LEGARMEH",
+        ),
+        HebrewAccent::Poetry(PoetryAccent::AzlaLegarmeh) if poetic => Matcher::Regex(
+            &RE_OUTER_POETRY_AZLA_LEGARMEH,
+            "RE_OUTER_POETRY_AZLA_LEGARMEH",
+        ),
+        HebrewAccent::Poetry(PoetryAccent::Tarcha) if poetic => Matcher::Char(TIPEHA),
+        HebrewAccent::Poetry(PoetryAccent::Pazer) if poetic => Matcher::Char(PAZER),
+        // Conjunctives
+        HebrewAccent::Poetry(PoetryAccent::Munach) if poetic => Matcher::Char(MUNAH),
+        HebrewAccent::Poetry(PoetryAccent::Merkha) if poetic => {
+            Matcher::Scanner(find_all_poetry_merkha)
+        }
+        HebrewAccent::Poetry(PoetryAccent::Illuy) if poetic => Matcher::Char(ILUY),
+        HebrewAccent::Poetry(PoetryAccent::Mehuppakh) if poetic => {
+            Matcher::Scanner(find_all_poetry_mehuppakh)
+        }
+        HebrewAccent::Poetry(PoetryAccent::Azla) if poetic => {
+            Matcher::Fancy(&FA_RE_OUTER_POETRY_AZLA, "FA_RE_OUTER_POETRY_AZLA")
+        }
+        HebrewAccent::Poetry(PoetryAccent::ShalsheletQetannah) if poetic => Matcher::Fancy(
+            &FA_RE_OUTER_POETRY_SHALSHELET_QETANNAH,
+            "FA_RE_OUTER_POETRY_SHALSHELET_QETANNAH",
+        ),
+        HebrewAccent::Poetry(PoetryAccent::TsinnoritMerkha) if poetic => Matcher::OuterInner(
+            &RE_OUTER_POETRY_TSINNORIT_MERKHA,
+            &RE_INNER_POETRY_TSINNORIT_MERKHA,
+            "RE_INNER_POETRY_TSINNORIT_MERKHA",
+        ),
+        HebrewAccent::Poetry(PoetryAccent::TsinnoritMahpakh) if poetic => Matcher::OuterInner(
+            &RE_OUTER_POETRY_TSINNORIT_MAHPAKH,
+            &RE_INNER_POETRY_TSINNORIT_MAHPAKH,
+            "RE_INNER_POETRY_TSINNORIT_MAHPAKH",
+        ),
+        HebrewAccent::Poetry(PoetryAccent::Galgal) if poetic => Matcher::Char(YERAH_BEN_YOMO),
+        HebrewAccent::Poetry(PoetryAccent::Meteg) if poetic => {
+            Matcher::Fancy(&FA_RE_OUTER_COMMON_METEG, "FA_RE_OUTER_COMMON_METEG")
+        }
+        _ => return None,
+    };
+    Some(matcher)
+}
+
+impl Matcher {
+    /// Returns all matches of the accent in the sentence, in the order in
+    /// which they occur
+    pub(crate) fn find_all<'h>(
+        &self,
+        sentence: &'h str,
+        accent: HebrewAccent,
+    ) -> Result<Vec<Match<'h>>, Error> {
+        let matches = match self {
+            Matcher::Char(c) => find_all_char(*c, sentence, accent),
+            Matcher::Regex(re, name) => re
+                .find_iter(sentence)
+                .map(|outer_match| {
+                    trace_match(name, accent, outer_match.start(), outer_match.end());
+                    Match::new(sentence, accent, outer_match.start(), outer_match.end())
+                })
+                .collect(),
+            Matcher::Fancy(re, name) => {
+                let mut matches = Vec::new();
+                for outer_match in re.find_iter(sentence) {
+                    let outer_match = outer_match?;
+                    trace_match(name, accent, outer_match.start(), outer_match.end());
+                    matches.push(Match::new(
+                        sentence,
+                        accent,
+                        outer_match.start(),
+                        outer_match.end(),
+                    ));
+                }
+                matches
+            }
+            Matcher::OuterInner(outer, inner, name) => outer
+                .find_iter(sentence)
+                .filter_map(|outer_match| {
+                    let outer_start = outer_match.start();
+                    inner.find(outer_match.as_str()).map(|inner_match| {
+                        let start = outer_start + inner_match.start();
+                        let end = outer_start + inner_match.end();
+                        trace_match(name, accent, start, end);
+                        Match::new(sentence, accent, start, end)
+                    })
+                })
+                .collect(),
+            Matcher::Scanner(scan) => scan(sentence),
+        };
+        if matches.is_empty() {
+            trace_event!(?accent, "no match");
+        }
+        Ok(matches)
+    }
+
+    /// Returns the first match of the accent in the sentence
+    pub(crate) fn find<'h>(
+        &self,
+        sentence: &'h str,
+        accent: HebrewAccent,
+    ) -> Result<Option<Match<'h>>, Error> {
+        Ok(match self {
+            Matcher::Char(c) => sentence
+                .find(*c)
+                .map(|index| Match::new(sentence, accent, index, index + c.len_utf8())),
+            Matcher::Regex(re, name) => re.find(sentence).map(|outer_match| {
+                trace_match(name, accent, outer_match.start(), outer_match.end());
+                Match::new(sentence, accent, outer_match.start(), outer_match.end())
+            }),
+            Matcher::Fancy(re, name) => re.find(sentence)?.map(|outer_match| {
+                trace_match(name, accent, outer_match.start(), outer_match.end());
+                Match::new(sentence, accent, outer_match.start(), outer_match.end())
+            }),
+            _ => self.find_all(sentence, accent)?.into_iter().next(),
+        })
+    }
+
+    /// Returns true if the accent is present in the sentence
+    pub(crate) fn is_match(&self, sentence: &str, accent: HebrewAccent) -> Result<bool, Error> {
+        Ok(match self {
+            Matcher::Char(c) => sentence.contains(*c),
+            Matcher::Regex(re, _) => re.is_match(sentence),
+            Matcher::Fancy(re, _) => re.is_match(sentence)?,
+            _ => self.find(sentence, accent)?.is_some(),
+        })
+    }
+}
+
+/// Returns the byte offsets of the code-point(s) of the accent within the
+/// match: the first code-point and, for an accent of two code-points, the last
+pub(crate) fn code_point_offsets(found: &Match) -> Vec<usize> {
+    let mut offsets = vec![found.start];
+    if found.accent.code_points() == 2 {
+        if let Some(last) = found.as_str().chars().next_back() {
+            offsets.push(found.end - last.len_utf8());
+        }
+    }
+    offsets
+}
+
+/* helper functions */
+
+/// All occurrences of a single code-point accent
+fn find_all_char(target_char: char, sentence: &str, accent: HebrewAccent) -> Vec<Match<'_>> {
+    sentence
+        .match_indices(target_char)
+        .map(|(index, _)| Match::new(sentence, accent, index, index + target_char.len_utf8()))
+        .collect()
+}
+
+// without the `tracing` feature the arguments are unused
+#[allow(unused_variables)]
+fn trace_match(name: &str, accent: HebrewAccent, start: usize, end: usize) {
+    trace_event!(regex = name, ?accent, start, end, "match found");
+}
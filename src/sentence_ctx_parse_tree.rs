@@ -0,0 +1,128 @@
+// Standard library
+
+// External crates
+
+// Local modules / crate‑internal
+use crate::{HebrewAccent, ParseNode, PoetryAccent, ProseAccent, SentenceContext, WordGroup};
+
+/// A word group and its strongest disjunctive accent (with its level)
+type Unit<'h> = (WordGroup<'h>, Option<(HebrewAccent, u8)>);
+
+impl SentenceContext {
+    /// Divides the verse by continuous dichotomy into the domains of the
+    /// disjunctive accents, taking into account the context.
+    ///
+    /// The domain of Silluq is divided by the strongest disjunctive within it
+    /// (e.g. Atnach), each part is divided again by its strongest disjunctive
+    /// (e.g. Segolta, Zaqeph, Tiphcha), down to the lowest disjunctives. Word groups
+    /// without a disjunctive accent are attached as servi to the following lord.
+    /// If a domain contains the same level more than once, every occurrence
+    /// starts a subdomain of its own.
+    ///
+    /// Returns None if the sentence has no words.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_accents::{Context, ProseAccent, SentenceContext};
+    ///
+    /// let sc = SentenceContext::new("בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים אֵ֥ת הַשָּׁמַ֖יִם וְאֵ֥ת הָאָֽרֶץ׃", Context::Prosaic);
+    /// let tree = sc.parse_tree().unwrap();
+    /// assert_eq!(tree.accent(), Some(ProseAccent::Silluq.into()));
+    /// assert_eq!(tree.children()[0].accent(), Some(ProseAccent::Atnach.into()));
+    /// // render the tree as an outline
+    /// assert!(tree.to_string().starts_with("Silluq: הָאָֽרֶץ\n  Atnach: אֱלֹהִ֑ים\n"));
+    /// ```
+    pub fn parse_tree(&self) -> Option<ParseNode<'_>> {
+        let units: Vec<Unit> = self
+            .word_groups()
+            .into_iter()
+            .map(|group| {
+                let lord = group
+                    .accents()
+                    .into_iter()
+                    .filter_map(|accent| level(accent).map(|level| (accent, level)))
+                    .min_by_key(|&(_, level)| level);
+                (group, lord)
+            })
+            .collect();
+        if units.is_empty() {
+            None
+        } else {
+            Some(divide(units))
+        }
+    }
+}
+
+/// Builds the domain of the last unit
+fn divide(mut units: Vec<Unit>) -> ParseNode {
+    let (lord, accent) = units.pop().expect("a domain has at least one unit");
+    let mut children = Vec::new();
+    while let Some(strongest) = units.iter().filter_map(|(_, d)| d.map(|(_, l)| l)).min() {
+        let split = units
+            .iter()
+            .position(|(_, d)| matches!(d, Some((_, l)) if *l == strongest))
+            .expect("the strongest level is present");
+        let rest = units.split_off(split + 1);
+        children.push(divide(std::mem::replace(&mut units, rest)));
+    }
+    ParseNode {
+        accent: accent.map(|(accent, _)| accent),
+        lord,
+        servi: units.into_iter().map(|(group, _)| group).collect(),
+        children,
+    }
+}
+
+/// The level of a disjunctive accent in the hierarchy, 0 is the strongest.
+/// Conjunctive and secondary accents have no level.
+pub(crate) fn level(accent: HebrewAccent) -> Option<u8> {
+    match accent {
+        HebrewAccent::Prose(accent) => prose_level(accent),
+        HebrewAccent::Poetry(accent) => poetry_level(accent),
+        HebrewAccent::Pseudo(_) => None,
+    }
+}
+
+/// Prose: emperors, kings, dukes and counts
+fn prose_level(accent: ProseAccent) -> Option<u8> {
+    match accent {
+        ProseAccent::Silluq => Some(0),
+        ProseAccent::Atnach => Some(1),
+        ProseAccent::Segolta
+        | ProseAccent::Shalshelet
+        | ProseAccent::ZaqephQaton
+        | ProseAccent::ZaqephGadol
+        | ProseAccent::Tiphcha => Some(2),
+        ProseAccent::Revia
+        | ProseAccent::Zarqa
+        | ProseAccent::Pashta
+        | ProseAccent::Yetiv
+        | ProseAccent::Tevir => Some(3),
+        ProseAccent::Geresh
+        | ProseAccent::Gershayim
+        | ProseAccent::Pazer
+        | ProseAccent::PazerGadol
+        | ProseAccent::TelishaGedolah
+        | ProseAccent::Legarmeh => Some(4),
+        _ => None,
+    }
+}
+
+/// Poetry: Oleh We Yored divides the verse before Atnach does
+fn poetry_level(accent: PoetryAccent) -> Option<u8> {
+    match accent {
+        PoetryAccent::Silluq => Some(0),
+        PoetryAccent::OlehWeYored => Some(1),
+        PoetryAccent::Atnach => Some(2),
+        PoetryAccent::ReviaGadol
+        | PoetryAccent::ReviaMugrash
+        | PoetryAccent::ShalsheletGadol
+        | PoetryAccent::Tsinnor
+        | PoetryAccent::ReviaQaton
+        | PoetryAccent::Dechi => Some(3),
+        PoetryAccent::Pazer | PoetryAccent::MehuppakhLegarmeh | PoetryAccent::AzlaLegarmeh => {
+            Some(4)
+        }
+        _ => None,
+    }
+}
@@ -19,6 +19,9 @@ const HEBREW: &str = r"\p{Hebrew}";
 /// Zero or one ordinary space.
 const OPTIONAL_SPACE: &str = r"\s?";
 
+/// Zero or more whitespace characters.
+const OPTIONAL_SPACES: &str = r"\s*";
+
 // One or more spaces (greedy).
 // ONE_OR_MORE_SPACES: &str = r"\s+";
 
@@ -38,8 +41,8 @@ const PASEQ_OR_VERTICAL_LINE: &str = r"[\u{05C0}\u{007C}]";
 const GERESH_OR_GERESH_MUQDAM: &str = r"[\u{059C}\u{059D}]";
 
 /// Negative LookAhead: *not* followed by Hebrew chars, optional spaces,
-/// and then a paseq or vertical line.
-const NOT_FOLLOWED_BY_PASEQ_OR_VERTICAL_LINE: &str = r"(?!\p{Hebrew}+?\s*[\u{05C0}\u{007C}])";
+/// and then a paseq or vertical line (the complement of a Legarmeh).
+const NOT_FOLLOWED_BY_PASEQ_OR_VERTICAL_LINE: &str = r"(?!\p{Hebrew}*?\s*[\u{05C0}\u{007C}])";
 
 /// Negative LookAhead: *not* followed by a Hebrew chars, a maqaf, and another
 /// Hebrew run.  This is used to exclude “maqqaf‑connected” sequences.
@@ -55,10 +58,11 @@ const OR: &str = "|";
 // Most of the time a sentence ends with Sof Pasuq (\u{05C3})
 // Some times a sentence ends with "samech" (U+05E1) or an "pey" (U+05E4).
 // Some times last words are connected by a Maqqeph (\u{05BE})
-//    FancyRegex::new(r"\u{05BD}(?!\p{Hebrew}*\u{05BE}\p{Hebrew}*)\p{Hebrew}*\s?\u{05C3}?\s?[\u{05E4}\u{05E1}]?\s$? regex")
+// The match is the Silluq mark only, the rest of the sentence is a lookahead
+//    FancyRegex::new(r"\u{05BD}(?=(?!\p{Hebrew}*\u{05BE}\p{Hebrew}*)\p{Hebrew}*\s?\u{05C3}\s?[\u{05E4}\u{05E1}]?\s?$)")
 pub(crate) static FA_RE_OUTER_COMMON_SILLUQ: Lazy<FancyRegex> = Lazy::new(|| {
     let pattern = format!(
-        "{SILLUQ}{NOT_FOLLOWED_BY_MAQAF}{HEBREW}*{OPTIONAL_SPACE}{SOF_PASUQ}{OPTIONAL_SPACE}{ZERO_OR_ONE_SAMECH_OR_PEY}{OPTIONAL_SPACE}$"
+        "{SILLUQ}(?={NOT_FOLLOWED_BY_MAQAF}{HEBREW}*{OPTIONAL_SPACE}{SOF_PASUQ}{OPTIONAL_SPACE}{ZERO_OR_ONE_SAMECH_OR_PEY}{OPTIONAL_SPACE}$)"
     );
     FancyRegex::new(&pattern)
         .unwrap_or_else(|_| panic!("Invalid regex FA_RE_OUTER_COMMON_SILLUQ: {}", &pattern))
@@ -68,16 +72,16 @@ pub(crate) static FA_RE_OUTER_COMMON_SILLUQ: Lazy<FancyRegex> = Lazy::new(|| {
 //      - Shalshelet (\u{0593}) followed by
 //      - Paseq (\u{05C0})
 // For readability a 'vertical line' (U+007C) is sometimes used instead of a Paseq
-// Regex::new(r"[^\s\u{05BE}]\p{Hebrew}*?\u{0593}\p{Hebrew}*?\s?[\u{05C0}\u{007C}]").unwrap()
+// Regex::new(r"[^\s\u{05BE}]\p{Hebrew}*?\u{0593}\p{Hebrew}*?\s*[\u{05C0}\u{007C}]").unwrap()
 pub(crate) static RE_OUTER_COMMON_SHALSHELET: Lazy<Regex> = Lazy::new(|| {
     let pattern = format!(
-        "{NOT_A_SPACE_OR_MAQAF}{HEBREW}*?{SHALSHELET}{HEBREW}*?{OPTIONAL_SPACE}{PASEQ_OR_VERTICAL_LINE}");
+        "{NOT_A_SPACE_OR_MAQAF}{HEBREW}*?{SHALSHELET}{HEBREW}*?{OPTIONAL_SPACES}{PASEQ_OR_VERTICAL_LINE}");
     Regex::new(&pattern)
         .unwrap_or_else(|_| panic!("Invalid regex RE_OUTER_COMMON_SHALSHELET: {}", &pattern))
 });
 
 pub(crate) static RE_INNER_COMMON_SHALSHELET: Lazy<Regex> = Lazy::new(|| {
-    let pattern = format!("{SHALSHELET}{HEBREW}*?{OPTIONAL_SPACE}{PASEQ_OR_VERTICAL_LINE}");
+    let pattern = format!("{SHALSHELET}{HEBREW}*?{OPTIONAL_SPACES}{PASEQ_OR_VERTICAL_LINE}");
     Regex::new(&pattern)
         .unwrap_or_else(|_| panic!("Invalid regex RE_INNER_COMMON_SHALSHELET: {}", &pattern))
 });
@@ -89,14 +93,14 @@ pub(crate) static RE_INNER_COMMON_SHALSHELET: Lazy<Regex> = Lazy::new(|| {
 // Regex::new(r"[^\s\u{05BE}]\p{Hebrew}*?\u{05A3}\p{Hebrew}*?\s*?[\u{05C0}\u{007C}]").unwrap()
 pub(crate) static RE_OUTER_PROSE_LEGARMEH: Lazy<Regex> = Lazy::new(|| {
     let pattern = format!(
-        "{NOT_A_SPACE_OR_MAQAF}{HEBREW}*?{MUNAH}{HEBREW}*?{OPTIONAL_SPACE}{PASEQ_OR_VERTICAL_LINE}"
+        "{NOT_A_SPACE_OR_MAQAF}{HEBREW}*?{MUNAH}{HEBREW}*?{OPTIONAL_SPACES}{PASEQ_OR_VERTICAL_LINE}"
     );
     Regex::new(&pattern)
         .unwrap_or_else(|_| panic!("Invalid regex RE_OUTER_PROSE_LEGARMEH: {}", &pattern))
 });
 
 pub(crate) static RE_INNER_PROSE_LEGARMEH: Lazy<Regex> = Lazy::new(|| {
-    let pattern = format!("{MUNAH}{HEBREW}*?{OPTIONAL_SPACE}{PASEQ_OR_VERTICAL_LINE}");
+    let pattern = format!("{MUNAH}{HEBREW}*?{OPTIONAL_SPACES}{PASEQ_OR_VERTICAL_LINE}");
     Regex::new(&pattern)
         .unwrap_or_else(|_| panic!("Invalid regex RE_INNER_PROSE_LEGARMEH: {}", &pattern))
 });
@@ -106,7 +110,7 @@ pub(crate) static RE_INNER_PROSE_LEGARMEH: Lazy<Regex> = Lazy::new(|| {
 //      - Munach (\u{05A3})
 //      - Paseq (\u{05C0})
 // For readability a 'vertical line' (U+007C) is sometimes used instead of a Paseq
-// FancyRegex::new(r"\u{05A3}(?!\p{Hebrew}*?\s*?[\u{05C0}\u{007C}])").unwrap());
+// FancyRegex::new(r"\u{05A3}(?!\p{Hebrew}*?\s*[\u{05C0}\u{007C}])").unwrap());
 pub(crate) static FA_RE_OUTER_PROSE_MUNACH: Lazy<FancyRegex> = Lazy::new(|| {
     let pattern = format!("{MUNAH}{NOT_FOLLOWED_BY_PASEQ_OR_VERTICAL_LINE}");
     FancyRegex::new(&pattern)
@@ -118,21 +122,19 @@ pub(crate) static FA_RE_OUTER_PROSE_MUNACH: Lazy<FancyRegex> = Lazy::new(|| {
 // Tiphcha: U+0596
 // Atnach:  U+0591
 // Silluq:  U+05BD (Meteg in the last word)
-//     Regex::new(r"(\u{0596}\p{Hebrew}+\u{0591}|\u{0596}\p{Hebrew}*?\u{05BD}\p{Hebrew}*?\s?[\u{05E4}\u{05E1}]?\s?$)").unwrap()
-pub(crate) static RE_OUTER_PROSE_MEAYLA: Lazy<Regex> = Lazy::new(|| {
-    let pattern = format!(
-        "{MEAYLA}{HEBREW}+{ATNACH}{OR}{MEAYLA}{HEBREW}*?{SILLUQ}{HEBREW}?{ZERO_OR_ONE_SAMECH_OR_PEY}{OPTIONAL_SPACE}"
-    );
-    Regex::new(&pattern)
-        .unwrap_or_else(|_| panic!("Invalid regex RE_OUTER_PROSE_MEAYLA: {}", &pattern))
+// The match is the Tiphcha mark only, the Atnach or Silluq is a lookahead
+//     FancyRegex::new(r"\u{0596}(?=\p{Hebrew}+\u{0591}|\p{Hebrew}*?\u{05BD})").unwrap()
+pub(crate) static FA_RE_OUTER_PROSE_MEAYLA: Lazy<FancyRegex> = Lazy::new(|| {
+    let pattern = format!("{MEAYLA}(?={HEBREW}+{ATNACH}{OR}{HEBREW}*?{SILLUQ})");
+    FancyRegex::new(&pattern)
+        .unwrap_or_else(|_| panic!("Invalid regex FA_RE_OUTER_PROSE_MEAYLA: {}", &pattern))
 });
 
-// TODO
-// A meteg is considered a meteg only when it is found in a word that is not the final word of a sentence.
-// A Silluq is not a Meteg
-//  FancyRegex::new(r"\u{05BD}(?!(?!\p{Hebrew}*\u{05BE}\p{Hebrew}*)\p{Hebrew}*\s?\u{05C3}?\s?[\u{05E4}\u{05E1}]?\s?$)")
+// A meteg is considered a meteg only when it is not a Silluq,
+// i.e. the lookahead is the complement of FA_RE_OUTER_COMMON_SILLUQ
+//  FancyRegex::new(r"\u{05BD}(?!(?!\p{Hebrew}*\u{05BE}\p{Hebrew}*)\p{Hebrew}*\s?\u{05C3}\s?[\u{05E4}\u{05E1}]?\s?$)")
 const NAAMVERZINNEN: &str =
-    r"(?!(?!\p{Hebrew}*\u{05BE}\p{Hebrew}*)\p{Hebrew}*\s?\u{05C3}?\s?[\u{05E4}\u{05E1}]?\s?$)";
+    r"(?!(?!\p{Hebrew}*\u{05BE}\p{Hebrew}*)\p{Hebrew}*\s?\u{05C3}\s?[\u{05E4}\u{05E1}]?\s?$)";
 pub(crate) static FA_RE_OUTER_COMMON_METEG: Lazy<FancyRegex> = Lazy::new(|| {
     let pattern = format!("{}{}", METEG, NAAMVERZINNEN,);
     FancyRegex::new(&pattern)
@@ -150,10 +152,14 @@ pub(crate) static FA_RE_OUTER_COMMON_METEG: Lazy<FancyRegex> = Lazy::new(|| {
 // An 'Ole We Yored' consists of the following two UTF-8 code-points
 //      - Ole (\u{05AB}) followed by
 //      - Yored (\u{05A5}) aka Merkha
-// This accent can stretch over two words (a.k.a. word-unit)
+// This accent can stretch over two words (a.k.a. word-unit), the nearest
+// Yored completes it
 // Regex::new(r"\u{05AB}\p{Hebrew}+\s?\p{Hebrew}*\u{05A5}").unwrap());
 pub(crate) static RE_OUTER_POETRY_OLEH_WE_YORED: Lazy<Regex> = Lazy::new(|| {
-    let pattern = format!("{}{}+{}{}*{}", OLEH, HEBREW, OPTIONAL_SPACE, HEBREW, YORED);
+    let pattern = format!(
+        "{}{}+?{}{}*?{}",
+        OLEH, HEBREW, OPTIONAL_SPACE, HEBREW, YORED
+    );
     Regex::new(&pattern)
         .unwrap_or_else(|_| panic!("Invalid regex RE_OUTER_POETRY_OLEH_WE_YORED: {}", &pattern))
 });
@@ -227,11 +233,12 @@ pub(crate) static FA_RE_OUTER_POETRY_SHALSHELET_QETANNAH: Lazy<FancyRegex> = Laz
 // A Tsinnorit Merkha consists of the following two UTF-8 code-points
 //      - Tsinnorit (\u{0598}) followed by
 //      - Merkha (\u{05A5})
-// This accent can occur in one or two words (a.k.a. word-unit)
+// This accent can occur in one or two words (a.k.a. word-unit), the nearest
+// Merkha or Mahpakh completes it
 //     Regex::new(r"[\s\u{05BE}]?\p{Hebrew}*?\u{0598}\p{Hebrew}+[\s\u{05BE}]?\p{Hebrew}*\u{05A5}")
 pub(crate) static RE_OUTER_POETRY_TSINNORIT_MERKHA: Lazy<Regex> = Lazy::new(|| {
     let pattern = format!(
-        "{SPACE_OR_MAQAF}?{HEBREW}*?{TSINNORIT}{HEBREW}+{SPACE_OR_MAQAF}?{HEBREW}*{MERKHA}"
+        "{SPACE_OR_MAQAF}?{HEBREW}*?{TSINNORIT}{HEBREW}+?{SPACE_OR_MAQAF}?{HEBREW}*?{MERKHA}"
     );
     Regex::new(&pattern).unwrap_or_else(|_| {
         panic!(
@@ -242,7 +249,7 @@ pub(crate) static RE_OUTER_POETRY_TSINNORIT_MERKHA: Lazy<Regex> = Lazy::new(|| {
 });
 
 pub(crate) static RE_INNER_POETRY_TSINNORIT_MERKHA: Lazy<Regex> = Lazy::new(|| {
-    let pattern = format!("{TSINNORIT}{HEBREW}+{SPACE_OR_MAQAF}?{HEBREW}*{MERKHA}");
+    let pattern = format!("{TSINNORIT}{HEBREW}+?{SPACE_OR_MAQAF}?{HEBREW}*?{MERKHA}");
     Regex::new(&pattern).unwrap_or_else(|_| {
         panic!(
             "Invalid regex RE_INNER_POETRY_TSINNORIT_MERKHA: {}",
@@ -254,11 +261,12 @@ pub(crate) static RE_INNER_POETRY_TSINNORIT_MERKHA: Lazy<Regex> = Lazy::new(|| {
 // A Tsinnorit Mahpakh consists of the following two UTF-8 code-points
 //      - Tsinnorit (\u{0598}) followed by
 //      - Mahpakh (\u{05A4})
-// This accent can occur in one or two words (a.k.a. word-unit)
+// This accent can occur in one or two words (a.k.a. word-unit), the nearest
+// Merkha or Mahpakh completes it
 // Regex::new(r"[\s\u{05BE}]?\p{Hebrew}*?\u{0598}\p{Hebrew}+[\s\u{05BE}]?\p{Hebrew}*\u{05A4}")
 pub(crate) static RE_OUTER_POETRY_TSINNORIT_MAHPAKH: Lazy<Regex> = Lazy::new(|| {
     let pattern = format!(
-        "{SPACE_OR_MAQAF}?{HEBREW}*?{TSINNORIT}{HEBREW}+{SPACE_OR_MAQAF}?{HEBREW}*{MAHPAKH}"
+        "{SPACE_OR_MAQAF}?{HEBREW}*?{TSINNORIT}{HEBREW}+?{SPACE_OR_MAQAF}?{HEBREW}*?{MAHPAKH}"
     );
     Regex::new(&pattern).unwrap_or_else(|_| {
         panic!(
@@ -269,7 +277,7 @@ pub(crate) static RE_OUTER_POETRY_TSINNORIT_MAHPAKH: Lazy<Regex> = Lazy::new(||
 });
 
 pub(crate) static RE_INNER_POETRY_TSINNORIT_MAHPAKH: Lazy<Regex> = Lazy::new(|| {
-    let pattern = format!("{TSINNORIT}{HEBREW}+{SPACE_OR_MAQAF}?{HEBREW}*{MAHPAKH}");
+    let pattern = format!("{TSINNORIT}{HEBREW}+?{SPACE_OR_MAQAF}?{HEBREW}*?{MAHPAKH}");
     Regex::new(&pattern).unwrap_or_else(|_| {
         panic!(
             "Invalid regex RE_OUTER_POETRY_TSINNORIT_MAHPAKH: {}",
@@ -0,0 +1,76 @@
+// Standard library
+use std::collections::HashMap;
+
+// External crates
+
+// Local modules / crate‑internal
+use crate::char::{DAGESH, METEG, QAMATS_QATAN, RAFE, SHEVA, SHIN_DOT, SIN_DOT};
+use crate::sentence_ctx_matcher::code_point_offsets;
+use crate::{AccentFilter, HebrewAccent, SentenceContext};
+
+impl SentenceContext {
+    /// Returns a copy of the sentence keeping only the accents selected by the
+    /// filter, taking into account the context.
+    ///
+    /// The consonants and spaces are always kept. Every accent mark is classified
+    /// first (see [`SentenceContext::analyze`]), the code-point(s) of an accent are
+    /// kept or dropped together. Accent marks that do not belong to any accent
+    /// of the context are dropped. Vowels and consonant points are kept or
+    /// dropped as a whole, see [`AccentFilter`].
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_accents::{AccentCategory, AccentFilter, Context, ProseAccent, SentenceContext};
+    ///
+    /// let sc = SentenceContext::new("בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים", Context::Prosaic);
+    /// let filter = AccentFilter::new().category(AccentCategory::Disjunctive);
+    /// assert_eq!(sc.render_filtered(&filter), "בְּרֵאשִׁ֖ית בָּרָא אֱלֹהִ֑ים");
+    /// let filter = AccentFilter::new()
+    ///     .accent(ProseAccent::Atnach.into())
+    ///     .vowels(false)
+    ///     .points(false);
+    /// assert_eq!(sc.render_filtered(&filter), "בראשית ברא אלה֑ים");
+    /// ```
+    pub fn render_filtered(&self, filter: &AccentFilter) -> String {
+        let marks = self.accent_marks();
+        self.sentence
+            .char_indices()
+            .filter(|(offset, c)| match marks.get(offset) {
+                Some(accent) => filter.selects(*accent),
+                None if is_accent_mark(*c) => false,
+                None if is_vowel(*c) => filter.vowels,
+                None if is_point(*c) => filter.points,
+                None => true,
+            })
+            .map(|(_, c)| c)
+            .collect()
+    }
+
+    /// Returns the accent of every classified accent mark, by byte offset
+    pub(crate) fn accent_marks(&self) -> HashMap<usize, HebrewAccent> {
+        let mut marks = HashMap::new();
+        for (accent, found) in self.analyze() {
+            for offset in code_point_offsets(&found) {
+                marks.insert(offset, accent);
+            }
+        }
+        marks
+    }
+}
+
+/* helper functions */
+
+/// Cantillation marks and Meteg (accents that might not be classified)
+fn is_accent_mark(c: char) -> bool {
+    matches!(c, '\u{0591}'..='\u{05AF}' | METEG)
+}
+
+/// Vowel points (niqqud)
+fn is_vowel(c: char) -> bool {
+    matches!(c, SHEVA..='\u{05BB}' | QAMATS_QATAN)
+}
+
+/// Consonant points
+fn is_point(c: char) -> bool {
+    matches!(c, DAGESH | RAFE | SHIN_DOT | SIN_DOT)
+}
@@ -0,0 +1,67 @@
+// Standard library
+
+// External crates
+
+// Local modules / crate‑internal
+use crate::{Accent, AccentCategory, AccentType, Diagnostic, HebrewAccent, SentenceContext};
+
+impl SentenceContext {
+    /// Checks the conjunctive accents (servi) before every disjunctive accent
+    /// against the servi allowed for that disjunctive accent, taking into account
+    /// the context (see [`Servi`](crate::Servi)).
+    ///
+    /// The verse is divided first (see [`SentenceContext::parse_tree`]), the servi
+    /// of each domain are the conjunctive accents directly preceding its disjunctive
+    /// accent. Returns a [`Diagnostic`] for every unexpected sequence, in order of
+    /// appearance. An empty result does not guarantee a correct accentuation.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_accents::{Context, ProseAccent, SentenceContext};
+    ///
+    /// // Munach before Atnach is expected
+    /// let sc = SentenceContext::new("בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים", Context::Prosaic);
+    /// assert!(sc.validate_servi().is_empty());
+    /// // Mahpakh before Atnach is not
+    /// let sc = SentenceContext::new("בְּרֵאשִׁ֖ית בָּרָ֤א אֱלֹהִ֑ים", Context::Prosaic);
+    /// let diagnostics = sc.validate_servi();
+    /// assert_eq!(diagnostics.len(), 1);
+    /// assert_eq!(diagnostics[0].accent, ProseAccent::Atnach.into());
+    /// assert_eq!(diagnostics[0].as_str(), "בָּרָ֤א");
+    /// ```
+    pub fn validate_servi(&self) -> Vec<Diagnostic<'_>> {
+        let Some(tree) = self.parse_tree() else {
+            return Vec::new();
+        };
+        let mut diagnostics = Vec::new();
+        for node in tree.iter() {
+            let Some(accent) = node.accent else {
+                continue;
+            };
+            let servi: Vec<HebrewAccent> = node
+                .servi
+                .iter()
+                .flat_map(|group| group.accents())
+                .filter(|servus| is_servus(*servus))
+                .collect();
+            let allowed = accent.servi().map_or(true, |rules| rules.allows(&servi));
+            if !allowed {
+                diagnostics.push(Diagnostic {
+                    haystack: &self.sentence,
+                    start: node.servi.first().map_or(0, |group| group.start),
+                    end: node.servi.last().map_or(0, |group| group.end),
+                    accent,
+                    servi,
+                });
+            }
+        }
+        diagnostics.sort_by_key(|diagnostic| diagnostic.start);
+        diagnostics
+    }
+}
+
+/// A conjunctive, primary accent (so no Meteg)
+fn is_servus(accent: HebrewAccent) -> bool {
+    accent.category() == Some(AccentCategory::Conjunctive)
+        && accent.accent_type() == Some(AccentType::Primary)
+}
@@ -0,0 +1,214 @@
+// Standard library
+
+// External crates
+
+// Local modules / crate‑internal
+use crate::char::{MAQQEPH, PASEQ, PE, SAMEKH, SOF_PASUQ, VERTICAL_LINE};
+use crate::{
+    Error, HebrewAccent, Match, PseudoAccent, SectionMarker, SentenceContext, Token, Word,
+    WordGroup,
+};
+
+impl SentenceContext {
+    /// Splits the sentence into tokens: word groups (words connected by a
+    /// Maqqeph), Paseq, Sof Pasuq and the section markers (פ or ס) after the
+    /// Sof Pasuq.
+    ///
+    /// Every word carries the accents it holds, taking into account the context
+    /// (see [`SentenceContext::analyze`]). An accent that consists of two
+    /// code-points belongs to the word of its first code-point. The Maqqeph,
+    /// Paseq and Sof Pasuq are tokens (or separators) and not part of the words.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_accents::{Context, ProseAccent, SentenceContext, Token};
+    ///
+    /// let sc = SentenceContext::new("וַיְהִי־עֶ֖רֶב וַֽיְהִי־בֹ֖קֶר י֥וֹם אֶחָֽד׃ פ", Context::Prosaic);
+    /// let tokens = sc.tokens();
+    /// assert_eq!(tokens.len(), 6);
+    /// if let Token::WordGroup(group) = &tokens[0] {
+    ///     assert_eq!(group.as_str(), "וַיְהִי־עֶ֖רֶב");
+    ///     assert_eq!(group.words().len(), 2);
+    ///     assert_eq!(group.accents(), vec![ProseAccent::Tiphcha.into()]);
+    /// }
+    /// ```
+    pub fn tokens(&self) -> Vec<Token<'_>> {
+        self.tokens_with(&self.analyze())
+    }
+
+    /// Splits the sentence into tokens, see [`SentenceContext::tokens`], with
+    /// the accents of an earlier `analyze()` of the sentence
+    pub(crate) fn tokens_with<'h>(
+        &'h self,
+        analysis: &[(HebrewAccent, Match<'h>)],
+    ) -> Vec<Token<'h>> {
+        let sentence = self.sentence.as_str();
+        let mut tokens = Vec::new();
+        let mut seen_soph_pasuq = false;
+        let mut chars = sentence.char_indices().peekable();
+        while let Some((start, c)) = chars.next() {
+            let end = start + c.len_utf8();
+            match c {
+                c if c.is_whitespace() => {}
+                PASEQ | VERTICAL_LINE => tokens.push(Token::Paseq(start..end)),
+                SOF_PASUQ => {
+                    seen_soph_pasuq = true;
+                    tokens.push(Token::SophPasuq(start..end));
+                }
+                _ => {
+                    let mut group_end = end;
+                    while let Some(&(index, next)) = chars.peek() {
+                        if is_token_break(next) {
+                            break;
+                        }
+                        group_end = index + next.len_utf8();
+                        chars.next();
+                    }
+                    let text = &sentence[start..group_end];
+                    match section_marker(text) {
+                        Some(marker) if seen_soph_pasuq => {
+                            tokens.push(Token::SectionMarker(marker, start..group_end))
+                        }
+                        _ => tokens.push(Token::WordGroup(split_group(sentence, start, group_end))),
+                    }
+                }
+            }
+        }
+        assign_accents(analysis, &mut tokens);
+        tokens
+    }
+
+    /// Returns the word groups (words connected by a Maqqeph) of the sentence,
+    /// see [`SentenceContext::tokens`]
+    pub fn word_groups(&self) -> Vec<WordGroup<'_>> {
+        groups_of(self.tokens())
+    }
+
+    /// Returns the words of the sentence, words connected by a Maqqeph are
+    /// returned separately, see [`SentenceContext::tokens`]
+    ///
+    /// # Panics
+    /// Panics if the backtrack limit of a regular expression is exceeded,
+    /// use [`SentenceContext::try_words`] to handle this case.
+    pub fn words(&self) -> Vec<Word<'_>> {
+        self.words_with(&self.analyze())
+    }
+
+    /// Returns the words of the sentence, like [`SentenceContext::words`], but
+    /// returns an error instead of panicking.
+    ///
+    /// # Errors
+    /// - [`Error::BacktrackLimitExceeded`] if the backtrack limit of a regular expression is exceeded
+    pub fn try_words(&self) -> Result<Vec<Word<'_>>, Error> {
+        Ok(self.words_with(&self.try_analyze()?))
+    }
+
+    /// Returns the words of the sentence, see [`SentenceContext::words`], with
+    /// the accents of an earlier `analyze()` of the sentence
+    pub(crate) fn words_with<'h>(
+        &'h self,
+        analysis: &[(HebrewAccent, Match<'h>)],
+    ) -> Vec<Word<'h>> {
+        groups_of(self.tokens_with(analysis))
+            .into_iter()
+            .flat_map(|group| group.words)
+            .collect()
+    }
+}
+
+/*
+helper functions
+*/
+
+/// Adds every accent of the analysis to the word containing its first code-point
+///
+/// Both the analysis and the words are ordered by their position in the
+/// sentence, so a single pass over both suffices.
+fn assign_accents<'h>(analysis: &[(HebrewAccent, Match<'h>)], tokens: &mut [Token<'h>]) {
+    let mut words = tokens
+        .iter_mut()
+        .filter_map(|token| match token {
+            Token::WordGroup(group) => Some(group.words.iter_mut()),
+            _ => None,
+        })
+        .flatten()
+        .peekable();
+    for (accent, found) in analysis {
+        if is_separator(*accent) {
+            continue;
+        }
+        while words.next_if(|word| word.end <= found.start()).is_some() {}
+        if let Some(word) = words.peek_mut() {
+            if word.start <= found.start() {
+                word.matches.push(*found);
+            }
+        }
+    }
+}
+
+/// The word groups among the tokens
+fn groups_of(tokens: Vec<Token<'_>>) -> Vec<WordGroup<'_>> {
+    tokens
+        .into_iter()
+        .filter_map(|token| match token {
+            Token::WordGroup(group) => Some(group),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Characters that end a word group
+fn is_token_break(c: char) -> bool {
+    c.is_whitespace() || matches!(c, PASEQ | VERTICAL_LINE | SOF_PASUQ)
+}
+
+/// Accents that are tokens or separators themselves
+fn is_separator(accent: HebrewAccent) -> bool {
+    matches!(
+        accent,
+        HebrewAccent::Pseudo(PseudoAccent::Maqqeph)
+            | HebrewAccent::Pseudo(PseudoAccent::Paseq)
+            | HebrewAccent::Pseudo(PseudoAccent::SophPasuq)
+    )
+}
+
+/// A single Pe or Samekh (optionally in braces, e.g. `{פ}`) marks a section
+fn section_marker(text: &str) -> Option<SectionMarker> {
+    let text = text.trim_start_matches('{').trim_end_matches('}');
+    let mut chars = text.chars();
+    match (chars.next(), chars.next()) {
+        (Some(PE), None) => Some(SectionMarker::Petuchah),
+        (Some(SAMEKH), None) => Some(SectionMarker::Setumah),
+        _ => None,
+    }
+}
+
+/// Splits a word group on the Maqqeph into its words
+fn split_group(sentence: &str, start: usize, end: usize) -> WordGroup<'_> {
+    let mut words = Vec::new();
+    let mut word_start = start;
+    for (offset, c) in sentence[start..end].char_indices() {
+        if c == MAQQEPH {
+            push_word(&mut words, sentence, word_start, start + offset);
+            word_start = start + offset + c.len_utf8();
+        }
+    }
+    push_word(&mut words, sentence, word_start, end);
+    WordGroup {
+        haystack: sentence,
+        start,
+        end,
+        words,
+    }
+}
+
+fn push_word<'h>(words: &mut Vec<Word<'h>>, sentence: &'h str, start: usize, end: usize) {
+    if start < end {
+        words.push(Word {
+            haystack: sentence,
+            start,
+            end,
+            matches: Vec::new(),
+        });
+    }
+}
@@ -0,0 +1,71 @@
+//! Serialization with `serde` (cargo feature `serde`)
+//!
+//! The accents are represented by their BHS name, e.g. `"Zaqeph Qaton"`, and
+//! deserialized with [`FromStr`], so any name of the accent is accepted (see
+//! [`ProseAccent::from_str`](crate::ProseAccent)). A [`Match`] is serialized
+//! with its text instead of the whole haystack.
+//!
+//! The static accent data (e.g. [`AccentInfo`](crate::AccentInfo)) can be
+//! serialized only.
+
+// Standard library
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+// External crates
+use serde::de::{self, Deserializer, Visitor};
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+
+// Crate‑internal (local modules)
+use crate::{Accent, Match, PoetryAccent, ProseAccent, PseudoAccent};
+
+macro_rules! serde_by_name {
+    ($accent:ty) => {
+        impl Serialize for $accent {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.english_name())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $accent {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserializer.deserialize_str(NameVisitor(PhantomData))
+            }
+        }
+    };
+}
+
+serde_by_name!(ProseAccent);
+serde_by_name!(PoetryAccent);
+serde_by_name!(PseudoAccent);
+
+/// Parses an accent from its name
+struct NameVisitor<A>(PhantomData<A>);
+
+impl<A: FromStr> Visitor<'_> for NameVisitor<A>
+where
+    A::Err: fmt::Display,
+{
+    type Value = A;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("the name of a Hebrew accent")
+    }
+
+    fn visit_str<E: de::Error>(self, name: &str) -> Result<A, E> {
+        name.parse().map_err(E::custom)
+    }
+}
+
+impl Serialize for Match<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Match", 4)?;
+        state.serialize_field("accent", &self.accent)?;
+        state.serialize_field("start", &self.start)?;
+        state.serialize_field("end", &self.end)?;
+        state.serialize_field("text", self.as_str())?;
+        state.end()
+    }
+}
@@ -0,0 +1,370 @@
+//! Statistics of the accents of many sentences (verses)
+//!
+//! A [`Statistics`] collects, over all sentences added to it:
+//!
+//! - the number of occurrences of every accent
+//! - the totals per category (conjunctive, disjunctive)
+//! - the sequences of two (bigrams) and three (trigrams) primary accents
+//! - the number of verses in which two primary accents occur together
+//! - the positions of the accents, as the index of the word counted from the
+//!   end of the verse (the last word has index 0)
+//!
+//! Collectors can be filled on separate threads and merged afterwards.
+
+// Standard library
+use std::collections::{BTreeMap, BTreeSet};
+use std::io;
+
+// External crates
+
+// Crate‑internal (local modules)
+#[cfg(not(feature = "serde"))]
+use crate::accent_export::json_str;
+use crate::accent_export::{csv_field, markdown_cell};
+use crate::{
+    Accent, AccentCategory, AccentType, Error, HebrewAccent, SentenceContext, TableFormat,
+};
+
+/// The columns of the CSV and Markdown formats
+const COLUMNS: [&str; 4] = ["statistic", "key", "position", "count"];
+
+/// Accent statistics of a collection of sentences
+///
+/// # Example
+/// ```
+/// use hebrew_accents::{Context, HebrewAccent, ProseAccent, SentenceContext, Statistics};
+///
+/// let verses = [
+///     SentenceContext::new("בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים", Context::Prosaic),
+///     SentenceContext::new("וַיֹּ֥אמֶר אֱלֹהִ֖ים", Context::Prosaic),
+/// ];
+/// let stats: Statistics = verses.iter().collect();
+/// let tiphcha = HebrewAccent::from(ProseAccent::Tiphcha);
+/// assert_eq!(stats.verses, 2);
+/// assert_eq!(stats.count(tiphcha), 2);
+/// assert_eq!(stats.positions[&tiphcha][&0], 1);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Statistics {
+    /// Number of sentences added
+    pub verses: usize,
+    /// Number of occurrences per accent
+    pub counts: BTreeMap<HebrewAccent, usize>,
+    /// Number of occurrences per category, accents without a category
+    /// (the pseudo accents) are not counted
+    pub categories: BTreeMap<AccentCategory, usize>,
+    /// Number of occurrences of two consecutive primary accents
+    pub bigrams: BTreeMap<[HebrewAccent; 2], usize>,
+    /// Number of occurrences of three consecutive primary accents
+    pub trigrams: BTreeMap<[HebrewAccent; 3], usize>,
+    /// Number of sentences containing both primary accents, the smaller accent first
+    pub co_occurrences: BTreeMap<(HebrewAccent, HebrewAccent), usize>,
+    /// Number of occurrences per accent and word index counted from the end
+    /// of the sentence
+    pub positions: BTreeMap<HebrewAccent, BTreeMap<usize, usize>>,
+}
+
+impl Statistics {
+    /// Creates an empty collector
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the accents of the sentence
+    ///
+    /// # Panics
+    /// Panics if the backtrack limit of a regular expression is exceeded,
+    /// use [`Statistics::try_add`] to handle this case.
+    pub fn add(&mut self, sc: &SentenceContext) {
+        self.try_add(sc)
+            .expect("analyzing the accents of the sentence failed")
+    }
+
+    /// Adds the accents of the sentence, like [`Statistics::add`], but returns
+    /// an error instead of panicking. The statistics are unchanged on error.
+    ///
+    /// # Errors
+    /// - [`Error::BacktrackLimitExceeded`] if the backtrack limit of a regular expression is exceeded
+    pub fn try_add(&mut self, sc: &SentenceContext) -> Result<(), Error> {
+        let analysis = sc.try_analyze()?;
+        self.verses += 1;
+        for (accent, _) in &analysis {
+            *self.counts.entry(*accent).or_default() += 1;
+            if let Some(category) = accent.category() {
+                *self.categories.entry(category).or_default() += 1;
+            }
+        }
+        let words = sc.words_with(&analysis);
+        let mut sequence = Vec::new();
+        for (idx, word) in words.iter().enumerate() {
+            let position = words.len() - 1 - idx;
+            for accent in word.accents() {
+                *self
+                    .positions
+                    .entry(accent)
+                    .or_default()
+                    .entry(position)
+                    .or_default() += 1;
+                if accent.accent_type() == Some(AccentType::Primary) {
+                    sequence.push(accent);
+                }
+            }
+        }
+        for pair in sequence.windows(2) {
+            *self.bigrams.entry([pair[0], pair[1]]).or_default() += 1;
+        }
+        for triple in sequence.windows(3) {
+            *self
+                .trigrams
+                .entry([triple[0], triple[1], triple[2]])
+                .or_default() += 1;
+        }
+        let distinct: Vec<HebrewAccent> = sequence
+            .into_iter()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        for (idx, first) in distinct.iter().enumerate() {
+            for second in &distinct[idx + 1..] {
+                *self.co_occurrences.entry((*first, *second)).or_default() += 1;
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds the statistics of another collector, e.g. one filled on another thread
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_accents::{Context, SentenceContext, Statistics};
+    /// use std::thread;
+    ///
+    /// let books = [["בָּרָ֣א אֱלֹהִ֑ים"], ["וַיֹּ֥אמֶר אֱלֹהִ֖ים"]];
+    /// let mut stats = Statistics::new();
+    /// thread::scope(|scope| {
+    ///     let handles: Vec<_> = books
+    ///         .iter()
+    ///         .map(|book| {
+    ///             scope.spawn(move || {
+    ///                 book.iter()
+    ///                     .map(|verse| SentenceContext::new(verse, Context::Prosaic))
+    ///                     .collect::<Vec<_>>()
+    ///                     .iter()
+    ///                     .collect::<Statistics>()
+    ///             })
+    ///         })
+    ///         .collect();
+    ///     for handle in handles {
+    ///         stats.merge(handle.join().unwrap());
+    ///     }
+    /// });
+    /// assert_eq!(stats.verses, 2);
+    /// ```
+    pub fn merge(&mut self, other: Statistics) {
+        self.verses += other.verses;
+        merge_counts(&mut self.counts, other.counts);
+        merge_counts(&mut self.categories, other.categories);
+        merge_counts(&mut self.bigrams, other.bigrams);
+        merge_counts(&mut self.trigrams, other.trigrams);
+        merge_counts(&mut self.co_occurrences, other.co_occurrences);
+        for (accent, positions) in other.positions {
+            merge_counts(self.positions.entry(accent).or_default(), positions);
+        }
+    }
+
+    /// Returns the number of occurrences of the accent
+    pub fn count(&self, accent: HebrewAccent) -> usize {
+        self.counts.get(&accent).copied().unwrap_or_default()
+    }
+
+    /// Returns the number of occurrences of all accents
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Returns the accents with their number of occurrences, most frequent first
+    pub fn most_frequent(&self) -> Vec<(HebrewAccent, usize)> {
+        let mut counts: Vec<(HebrewAccent, usize)> =
+            self.counts.iter().map(|(a, c)| (*a, *c)).collect();
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        counts
+    }
+
+    /// Writes the statistics in the format, one row per counted item
+    ///
+    /// Every row holds the statistic (`verses`, `accent`, `category`, `bigram`,
+    /// `trigram`, `co_occurrence` or `position`), the key (the BHS names of the
+    /// accents prefixed with their system, e.g. `prose:Atnach` or `poetry:Atnach`,
+    /// sequences joined with `" > "`, pairs with `" + "`), the word position
+    /// (for `position` only) and the count.
+    ///
+    /// # Errors
+    /// Returns the error of the writer.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_accents::{Context, SentenceContext, Statistics, TableFormat};
+    ///
+    /// let mut stats = Statistics::new();
+    /// stats.add(&SentenceContext::new("בָּרָ֣א אֱלֹהִ֑ים", Context::Prosaic));
+    /// let mut csv = Vec::new();
+    /// stats.write(&mut csv, TableFormat::Csv).unwrap();
+    /// let csv = String::from_utf8(csv).unwrap();
+    /// assert!(csv.starts_with("statistic,key,position,count\nverses,,,1\n"));
+    /// assert!(csv.contains("\nbigram,prose:Munach > prose:Atnach,,1\n"));
+    /// ```
+    pub fn write<W: io::Write>(&self, writer: &mut W, format: TableFormat) -> io::Result<()> {
+        let rows = self.rows();
+        match format {
+            #[cfg(feature = "serde")]
+            TableFormat::Json => {
+                let rows: Vec<JsonRow> = rows
+                    .iter()
+                    .map(|(statistic, key, position, count)| JsonRow {
+                        statistic,
+                        key,
+                        position: *position,
+                        count: *count,
+                    })
+                    .collect();
+                serde_json::to_writer_pretty(&mut *writer, &rows)?;
+                writeln!(writer)
+            }
+            #[cfg(not(feature = "serde"))]
+            TableFormat::Json => {
+                writeln!(writer, "[")?;
+                for (idx, (statistic, key, position, count)) in rows.iter().enumerate() {
+                    let position = position.map_or("null".to_string(), |p| p.to_string());
+                    let separator = if idx + 1 < rows.len() { "," } else { "" };
+                    writeln!(
+                        writer,
+                        "  {{\"statistic\": {}, \"key\": {}, \"position\": {}, \"count\": {}}}{}",
+                        json_str(statistic),
+                        json_str(key),
+                        position,
+                        count,
+                        separator
+                    )?;
+                }
+                writeln!(writer, "]")
+            }
+            TableFormat::Csv => {
+                writeln!(writer, "{}", COLUMNS.join(","))?;
+                for (statistic, key, position, count) in rows {
+                    let position = position.map_or(String::new(), |p| p.to_string());
+                    writeln!(
+                        writer,
+                        "{},{},{},{}",
+                        statistic,
+                        csv_field(&key),
+                        position,
+                        count
+                    )?;
+                }
+                Ok(())
+            }
+            TableFormat::Markdown => {
+                writeln!(writer, "| {} |", COLUMNS.join(" | "))?;
+                writeln!(writer, "|{}", "---|".repeat(COLUMNS.len()))?;
+                for (statistic, key, position, count) in rows {
+                    let position = position.map_or(String::new(), |p| p.to_string());
+                    writeln!(
+                        writer,
+                        "| {} | {} | {} | {} |",
+                        statistic,
+                        markdown_cell(&key),
+                        position,
+                        count
+                    )?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// The rows of the export: statistic, key, position and count
+    fn rows(&self) -> Vec<(&'static str, String, Option<usize>, usize)> {
+        let mut rows = vec![("verses", String::new(), None, self.verses)];
+        for (accent, count) in &self.counts {
+            rows.push(("accent", name(*accent), None, *count));
+        }
+        for (category, count) in &self.categories {
+            rows.push(("category", format!("{:?}", category), None, *count));
+        }
+        for (bigram, count) in &self.bigrams {
+            rows.push(("bigram", join(bigram, " > "), None, *count));
+        }
+        for (trigram, count) in &self.trigrams {
+            rows.push(("trigram", join(trigram, " > "), None, *count));
+        }
+        for ((first, second), count) in &self.co_occurrences {
+            rows.push((
+                "co_occurrence",
+                join(&[*first, *second], " + "),
+                None,
+                *count,
+            ));
+        }
+        for (accent, positions) in &self.positions {
+            for (position, count) in positions {
+                rows.push(("position", name(*accent), Some(*position), *count));
+            }
+        }
+        rows
+    }
+}
+
+impl<'a> FromIterator<&'a SentenceContext> for Statistics {
+    fn from_iter<I: IntoIterator<Item = &'a SentenceContext>>(iter: I) -> Self {
+        let mut stats = Statistics::new();
+        stats.extend(iter);
+        stats
+    }
+}
+
+impl<'a> Extend<&'a SentenceContext> for Statistics {
+    fn extend<I: IntoIterator<Item = &'a SentenceContext>>(&mut self, iter: I) {
+        for sc in iter {
+            self.add(sc);
+        }
+    }
+}
+
+/// A row of the JSON format
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+struct JsonRow<'a> {
+    statistic: &'a str,
+    key: &'a str,
+    position: Option<usize>,
+    count: usize,
+}
+
+/* helper functions */
+
+/// Adds the counts of `other` to `counts`
+fn merge_counts<K: Ord>(counts: &mut BTreeMap<K, usize>, other: BTreeMap<K, usize>) {
+    for (key, count) in other {
+        *counts.entry(key).or_default() += count;
+    }
+}
+
+/// The BHS name of the accent prefixed with its system, as the prose and
+/// poetry accents share names (e.g. `prose:Atnach` and `poetry:Atnach`)
+fn name(accent: HebrewAccent) -> String {
+    let system = match accent {
+        HebrewAccent::Prose(_) => "prose",
+        HebrewAccent::Poetry(_) => "poetry",
+        HebrewAccent::Pseudo(_) => "pseudo",
+    };
+    format!("{}:{}", system, accent.english_name())
+}
+
+/// The names of the accents (see [`name`]) joined with the separator
+fn join(accents: &[HebrewAccent], separator: &str) -> String {
+    accents
+        .iter()
+        .map(|accent| name(*accent))
+        .collect::<Vec<_>>()
+        .join(separator)
+}
@@ -0,0 +1,399 @@
+//! Syllables and the stressed syllable of a word
+//!
+//! The segmentation works on pointed Hebrew (with vowel points) and follows the
+//! common rules of the grammars:
+//!
+//!   - every full vowel (including Shureq and Holem Male) forms one syllable
+//!   - a vocal Shewa or a Hateph vowel belongs to the following syllable
+//!   - a silent Shewa, a vowel letter (Mater Lectionis) and a Furtive Patach
+//!     belong to the preceding syllable
+//!
+//! The Shewa after a long vowel is always regarded as silent.
+
+// Standard library
+use std::ops::Range;
+
+// External crates
+
+// Crate‑internal (local modules)
+use crate::char::{
+    ALEF, AYIN, DAGESH, HATAF_PATAH, HATAF_QAMATS, HATAF_SEGOL, HE, HET, HIRIQ, HOLAM,
+    HOLAM_HASER_FOR_VAV, PATAH, QAMATS, QAMATS_QATAN, QUBUTS, SEGOL_POINT, SHEVA, TSERE, VAV, YOD,
+};
+use crate::{Accent, AccentType, Match, Word, WordStress};
+
+/// A single syllable of a word
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Syllable<'h> {
+    /// The sentence in which the syllable is found
+    pub haystack: &'h str,
+    /// Start byte of the syllable
+    pub start: usize,
+    /// End byte of the syllable
+    pub end: usize,
+    /// The vowel point of the syllable (Shureq is returned as Dagesh)
+    pub vowel: char,
+    /// True if the syllable ends in a vowel
+    pub open: bool,
+    /// True if the syllable carries the word stress
+    pub stressed: bool,
+}
+
+impl<'h> Syllable<'h> {
+    /// Returns the byte offset of the start of the syllable in the haystack
+    #[inline]
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the byte offset of the end of the syllable in the haystack
+    #[inline]
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Returns the range over the starting and ending byte offsets of the syllable
+    #[inline]
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Returns the text of the syllable
+    #[inline]
+    pub fn as_str(&self) -> &'h str {
+        &self.haystack[self.range()]
+    }
+}
+
+impl<'h> Word<'h> {
+    /// Splits the (pointed) word into syllables and marks the stressed syllable
+    ///
+    /// The stressed syllable is derived from the accents of the word:
+    ///
+    ///   - an impositive accent is located on the stressed syllable
+    ///   - a prepositive (e.g. Telisha Gedolah) or postpositive (e.g. Segolta,
+    ///     Pashta) accent is printed at the edge of the word, the stress is on the
+    ///     syllable of a repeated mark (e.g. a double Pashta), otherwise on the
+    ///     default syllable
+    ///
+    /// Without such an accent the stress is on the last syllable (Milra), except
+    /// for Segolates which are stressed on the penultimate syllable (Mil'el).
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_accents::{Context, SentenceContext};
+    ///
+    /// let sc = SentenceContext::new("וַיַּ֥רְא אֱלֹהִ֖ים", Context::Prosaic);
+    /// let words = sc.words();
+    /// let syllables: Vec<&str> = words[1].syllables().iter().map(|s| s.as_str()).collect();
+    /// assert_eq!(syllables, vec!["אֱלֹ", "הִ֖ים"]);
+    /// assert_eq!(words[1].stressed_syllable().unwrap().as_str(), "הִ֖ים");
+    /// ```
+    pub fn syllables(&self) -> Vec<Syllable<'h>> {
+        let mut syllables = segment(self.haystack, self.start, self.end);
+        if let Some(index) = stressed_index(&syllables, &self.matches) {
+            syllables[index].stressed = true;
+        }
+        syllables
+    }
+
+    /// Returns the stressed syllable of the word, see [`Word::syllables`]
+    pub fn stressed_syllable(&self) -> Option<Syllable<'h>> {
+        self.syllables()
+            .into_iter()
+            .find(|syllable| syllable.stressed)
+    }
+}
+
+/// A letter including its points and accents
+#[derive(Debug, Clone, Copy)]
+struct Cluster {
+    start: usize,
+    end: usize,
+    letter: Option<char>,
+    vowel: Option<char>,
+    dagesh: bool,
+    role: Role,
+}
+
+/// The role of a letter within its syllable
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Role {
+    /// Carries the (full) vowel of the syllable
+    Nucleus,
+    /// Vocal Shewa or Hateph vowel, opens the next syllable
+    Onset,
+    /// Closes the syllable (silent Shewa, vowel letter, Furtive Patach)
+    Coda,
+}
+
+/// Splits the haystack[start..end] into syllables
+fn segment(haystack: &str, start: usize, end: usize) -> Vec<Syllable<'_>> {
+    let mut clusters = clusters(haystack, start, end);
+    resolve_vowel_letters(&mut clusters);
+    resolve_furtive_patach(&mut clusters);
+    resolve_shewa(&mut clusters);
+
+    let mut syllables: Vec<Syllable> = Vec::new();
+    let mut onset: Option<usize> = None;
+    for (index, cluster) in clusters.iter().enumerate() {
+        match cluster.role {
+            Role::Nucleus => {
+                // a doubled consonant (Dagesh Forte) closes the previous syllable
+                if cluster.dagesh && index > 0 && onset.is_none() {
+                    if let Some(previous) = syllables.last_mut() {
+                        if previous.end == cluster.start {
+                            previous.open = false;
+                        }
+                    }
+                }
+                syllables.push(Syllable {
+                    haystack,
+                    start: onset.take().unwrap_or(cluster.start),
+                    end: cluster.end,
+                    vowel: cluster.vowel.unwrap_or(DAGESH),
+                    open: true,
+                    stressed: false,
+                });
+            }
+            Role::Onset => {
+                onset.get_or_insert(cluster.start);
+            }
+            Role::Coda => match syllables.last_mut() {
+                Some(previous) if onset.is_none() => {
+                    previous.end = cluster.end;
+                    if !is_vowel_letter(cluster) {
+                        previous.open = false;
+                    }
+                }
+                _ => {
+                    onset.get_or_insert(cluster.start);
+                }
+            },
+        }
+    }
+    // remaining letters without a vowel belong to the last syllable
+    if let (Some(onset_start), Some(previous)) = (onset, syllables.last_mut()) {
+        if onset_start >= previous.end {
+            previous.end = end;
+        }
+    }
+    syllables
+}
+
+/// Groups every letter together with its points and accents
+fn clusters(haystack: &str, start: usize, end: usize) -> Vec<Cluster> {
+    let mut clusters: Vec<Cluster> = Vec::new();
+    for (offset, c) in haystack[start..end].char_indices() {
+        let position = start + offset;
+        if is_letter(c) || clusters.is_empty() {
+            clusters.push(Cluster {
+                start: position,
+                end: position,
+                letter: is_letter(c).then_some(c),
+                vowel: None,
+                dagesh: false,
+                role: Role::Coda,
+            });
+        }
+        let cluster = clusters.last_mut().expect("at least one cluster");
+        cluster.end = position + c.len_utf8();
+        match c {
+            DAGESH => cluster.dagesh = true,
+            c if is_vowel(c) || c == SHEVA => cluster.vowel = Some(c),
+            _ => {}
+        }
+    }
+    for cluster in clusters.iter_mut() {
+        cluster.role = match cluster.vowel {
+            Some(SHEVA) | None => Role::Coda,
+            Some(HATAF_SEGOL) | Some(HATAF_PATAH) | Some(HATAF_QAMATS) => Role::Onset,
+            Some(_) => Role::Nucleus,
+        };
+    }
+    clusters
+}
+
+/// Shureq and Holem Male are the vowel of the preceding letter
+fn resolve_vowel_letters(clusters: &mut [Cluster]) {
+    for index in 0..clusters.len() {
+        let cluster = clusters[index];
+        if cluster.letter != Some(VAV) {
+            continue;
+        }
+        let is_shureq = cluster.dagesh && cluster.vowel.is_none();
+        let is_holem_male = cluster.vowel == Some(HOLAM);
+        if !is_shureq && !is_holem_male {
+            continue;
+        }
+        let vowel = if is_shureq { DAGESH } else { HOLAM };
+        match index.checked_sub(1).map(|previous| clusters[previous]) {
+            // the conjunction Waw pointed as Shureq
+            None => {
+                clusters[index].vowel = Some(vowel);
+                clusters[index].role = Role::Nucleus;
+            }
+            Some(previous) if previous.vowel.is_none() => {
+                clusters[index - 1].vowel = Some(vowel);
+                clusters[index - 1].role = Role::Nucleus;
+                // the Waw is a vowel letter, the dot is not a Dagesh
+                clusters[index].vowel = None;
+                clusters[index].dagesh = false;
+                clusters[index].role = Role::Coda;
+            }
+            // e.g. a consonantal Waw with a Holem (or Shureq)
+            Some(_) => {
+                clusters[index].vowel = Some(vowel);
+                clusters[index].role = Role::Nucleus;
+            }
+        }
+    }
+}
+
+/// A Patach under a final Chet, Ayin or He (with Mappiq) after a vowel is
+/// pronounced before the consonant and does not form a syllable
+fn resolve_furtive_patach(clusters: &mut [Cluster]) {
+    let Some(last) = clusters.len().checked_sub(1) else {
+        return;
+    };
+    let cluster = clusters[last];
+    let guttural = match cluster.letter {
+        Some(HET) | Some(AYIN) => true,
+        Some(HE) => cluster.dagesh,
+        _ => false,
+    };
+    if !guttural || cluster.vowel != Some(PATAH) || last == 0 {
+        return;
+    }
+    let previous = clusters[last - 1];
+    if previous.role == Role::Nucleus || is_vowel_letter(&previous) {
+        clusters[last].role = Role::Coda;
+    }
+}
+
+/// A Shewa is vocal at the start of the word, after another Shewa
+/// and under a doubled consonant (Dagesh Forte)
+fn resolve_shewa(clusters: &mut [Cluster]) {
+    let last = clusters.len().saturating_sub(1);
+    for index in 0..clusters.len() {
+        if clusters[index].vowel != Some(SHEVA) || index == last {
+            continue;
+        }
+        let vocal = match index.checked_sub(1).map(|previous| clusters[previous]) {
+            None => true,
+            Some(previous) if previous.vowel == Some(SHEVA) => previous.role != Role::Onset,
+            Some(previous) => clusters[index].dagesh && previous.role == Role::Nucleus,
+        };
+        if vocal {
+            clusters[index].role = Role::Onset;
+        }
+    }
+}
+
+/// Index of the stressed syllable
+fn stressed_index(syllables: &[Syllable], matches: &[Match]) -> Option<usize> {
+    if syllables.is_empty() {
+        return None;
+    }
+    let syllable_of = |position: usize| {
+        syllables
+            .iter()
+            .position(|syllable| syllable.start <= position && position < syllable.end)
+    };
+    // the main accent (disjunctive) goes before a conjunctive accent
+    let mut candidates: Vec<&Match> = matches
+        .iter()
+        .filter(|found| found.accent.accent_type() == Some(AccentType::Primary))
+        .collect();
+    candidates.sort_by_key(|found| found.accent.category());
+    for found in candidates.iter().rev() {
+        match found.accent.word_stress() {
+            Some(WordStress::ImPositive) => {
+                if let Some(index) = syllable_of(stress_position(found, syllables)) {
+                    return Some(index);
+                }
+            }
+            Some(WordStress::PrePositive) | Some(WordStress::PostPositive) => {
+                // a repeated mark indicates the stressed syllable
+                let edge = syllable_of(found.start);
+                let repeated = candidates
+                    .iter()
+                    .filter(|other| other.accent == found.accent)
+                    .filter_map(|other| syllable_of(other.start))
+                    .find(|&index| Some(index) != edge);
+                if repeated.is_some() {
+                    return repeated;
+                }
+            }
+            _ => {}
+        }
+    }
+    Some(default_stress(syllables))
+}
+
+/// The position of the mark that indicates the stress, for an accent with two
+/// code-points this is the second one (if it is located in the same word)
+fn stress_position(found: &Match, syllables: &[Syllable]) -> usize {
+    let word_end = syllables.last().map_or(found.end, |syllable| syllable.end);
+    match found.as_str().chars().next_back() {
+        Some(last) if found.accent.code_points() == 2 && found.end <= word_end => {
+            found.end - last.len_utf8()
+        }
+        _ => found.start,
+    }
+}
+
+/// Milra (last syllable) except for the Segolates (e.g. מֶלֶךְ, סֵפֶר, בֹּקֶר)
+fn default_stress(syllables: &[Syllable]) -> usize {
+    let last = syllables.len() - 1;
+    if last > 0 {
+        let ultima = syllables[last];
+        let penultima = syllables[last - 1];
+        let is_segolate = ultima.vowel == SEGOL_POINT
+            && !ultima.open
+            && matches!(
+                penultima.vowel,
+                SEGOL_POINT | TSERE | HOLAM | PATAH | QAMATS
+            );
+        if is_segolate {
+            return last - 1;
+        }
+    }
+    last
+}
+
+/*
+helper functions
+*/
+
+fn is_letter(c: char) -> bool {
+    ('\u{05D0}'..='\u{05EA}').contains(&c)
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(
+        c,
+        HATAF_SEGOL
+            | HATAF_PATAH
+            | HATAF_QAMATS
+            | HIRIQ
+            | TSERE
+            | SEGOL_POINT
+            | PATAH
+            | QAMATS
+            | HOLAM
+            | HOLAM_HASER_FOR_VAV
+            | QUBUTS
+            | QAMATS_QATAN
+    )
+}
+
+/// A quiescent Alef, He, Waw or Yod (Mater Lectionis)
+fn is_vowel_letter(cluster: &Cluster) -> bool {
+    cluster.vowel.is_none()
+        && !cluster.dagesh
+        && matches!(
+            cluster.letter,
+            Some(ALEF) | Some(HE) | Some(VAV) | Some(YOD)
+        )
+}
@@ -0,0 +1,15 @@
+//! Diagnostic output
+//!
+//! With the `tracing` feature enabled, the search routines emit `tracing` debug events
+//! recording which regex or scanner decided a classification. Without the feature
+//! the events are compiled out and the library is silent.
+
+/// Emits a `tracing` debug event, or nothing if the `tracing` feature is disabled
+macro_rules! trace_event {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "tracing")]
+        tracing::debug!($($arg)*);
+    };
+}
+
+pub(crate) use trace_event;
@@ -0,0 +1,161 @@
+//! Words and word groups (accent units) of a sentence
+//!
+
+// Standard library
+use std::ops::Range;
+
+// External crates
+
+// Crate‑internal (local modules)
+use crate::{Accent, AccentCategory, AccentType, HebrewAccent, Match};
+
+/// A single word of a sentence, without a trailing Maqqeph
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Word<'h> {
+    /// The sentence in which the word is found
+    pub haystack: &'h str,
+    /// Start byte of the word
+    pub start: usize,
+    /// End byte of the word
+    pub end: usize,
+    /// The accents of the word, in order of appearance
+    pub matches: Vec<Match<'h>>,
+}
+
+impl<'h> Word<'h> {
+    /// Returns the byte offset of the start of the word in the haystack
+    #[inline]
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the byte offset of the end of the word in the haystack
+    #[inline]
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Returns the range over the starting and ending byte offsets of the word
+    #[inline]
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Returns the text of the word
+    #[inline]
+    pub fn as_str(&self) -> &'h str {
+        &self.haystack[self.range()]
+    }
+
+    /// Returns the accents of the word, in order of appearance
+    pub fn accents(&self) -> Vec<HebrewAccent> {
+        self.matches.iter().map(|found| found.accent).collect()
+    }
+
+    /// Returns the matches of the accents of the word, in order of appearance
+    #[inline]
+    pub fn matches(&self) -> &[Match<'h>] {
+        &self.matches
+    }
+
+    /// Returns the accent characterizing the word: the first disjunctive, or else
+    /// the first primary accent (e.g. not a Meteg), or else the first accent
+    pub fn principal_accent(&self) -> Option<HebrewAccent> {
+        let rank = |accent: &HebrewAccent| match (accent.category(), accent.accent_type()) {
+            (Some(AccentCategory::Disjunctive), Some(AccentType::Primary)) => 0,
+            (_, Some(AccentType::Primary)) => 1,
+            _ => 2,
+        };
+        self.accents().into_iter().min_by_key(rank)
+    }
+}
+
+/// One or more words connected by a Maqqeph, forming a single accent unit
+///
+/// A word without a Maqqeph is a group with only one word.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct WordGroup<'h> {
+    /// The sentence in which the word group is found
+    pub haystack: &'h str,
+    /// Start byte of the word group
+    pub start: usize,
+    /// End byte of the word group
+    pub end: usize,
+    /// The words of the group
+    pub words: Vec<Word<'h>>,
+}
+
+impl<'h> WordGroup<'h> {
+    /// Returns the byte offset of the start of the word group in the haystack
+    #[inline]
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the byte offset of the end of the word group in the haystack
+    #[inline]
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Returns the range over the starting and ending byte offsets of the word group
+    #[inline]
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Returns the text of the word group, including the Maqqeph(s)
+    #[inline]
+    pub fn as_str(&self) -> &'h str {
+        &self.haystack[self.range()]
+    }
+
+    /// Returns the words of the group
+    #[inline]
+    pub fn words(&self) -> &[Word<'h>] {
+        &self.words
+    }
+
+    /// Returns the accents of all words in the group, in order of appearance
+    pub fn accents(&self) -> Vec<HebrewAccent> {
+        self.words
+            .iter()
+            .flat_map(|word| word.matches.iter().map(|found| found.accent))
+            .collect()
+    }
+}
+
+/// Section marker at the end of a verse
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum SectionMarker {
+    /// Open section, marked with the letter Pe (פ)
+    Petuchah,
+    /// Closed section, marked with the letter Samekh (ס)
+    Setumah,
+}
+
+/// A token of a sentence, see [`SentenceContext::tokens`](crate::SentenceContext::tokens)
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Token<'h> {
+    /// A word, or words connected by a Maqqeph
+    WordGroup(WordGroup<'h>),
+    /// A Paseq (or vertical line), with its byte range
+    Paseq(Range<usize>),
+    /// A Sof Pasuq, with its byte range
+    SophPasuq(Range<usize>),
+    /// A section marker after the Sof Pasuq, with its byte range
+    SectionMarker(SectionMarker, Range<usize>),
+}
+
+impl Token<'_> {
+    /// Returns the range over the starting and ending byte offsets of the token
+    pub fn range(&self) -> Range<usize> {
+        match self {
+            Token::WordGroup(group) => group.range(),
+            Token::Paseq(range) | Token::SophPasuq(range) | Token::SectionMarker(_, range) => {
+                range.clone()
+            }
+        }
+    }
+}
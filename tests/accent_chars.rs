@@ -0,0 +1,55 @@
+use hebrew_accents::{
+    accents_for_char, chars_for_accent, Accent, Context, HebrewAccent, PoetryAccent, ProseAccent,
+    PseudoAccent,
+};
+
+#[test]
+fn test_accents_for_char_merkha() {
+    assert_eq!(
+        accents_for_char('\u{05A5}', Context::Poetic),
+        [
+            PoetryAccent::OlehWeYored.into(),
+            PoetryAccent::Merkha.into(),
+            PoetryAccent::TsinnoritMerkha.into()
+        ]
+    );
+    assert_eq!(
+        accents_for_char('\u{05A5}', Context::Prosaic),
+        [ProseAccent::Merkha.into()]
+    );
+}
+#[test]
+fn test_accents_for_char_meteg() {
+    assert_eq!(
+        accents_for_char('\u{05BD}', Context::Prosaic),
+        [ProseAccent::Silluq.into(), ProseAccent::Meteg.into()]
+    );
+    // the Paseq is part of Legarmeh and a pseudo accent of its own
+    let candidates = accents_for_char('\u{05C0}', Context::Prosaic);
+    assert!(candidates.contains(&ProseAccent::Legarmeh.into()));
+    assert_eq!(candidates.last(), Some(&PseudoAccent::Paseq.into()));
+}
+#[test]
+fn test_chars_for_accent_inverse() {
+    let accents = ProseAccent::ALL
+        .map(HebrewAccent::from)
+        .into_iter()
+        .chain(PoetryAccent::ALL.map(HebrewAccent::from))
+        .chain(PseudoAccent::ALL.map(HebrewAccent::from));
+    for accent in accents {
+        let chars = chars_for_accent(accent);
+        assert_eq!(
+            chars.len(),
+            usize::from(accent.code_points()),
+            "{:?}",
+            accent
+        );
+        let ctx = match accent {
+            HebrewAccent::Poetry(_) => Context::Poetic,
+            _ => Context::Prosaic,
+        };
+        for c in chars {
+            assert!(accents_for_char(c, ctx).contains(&accent), "{:?}", accent);
+        }
+    }
+}
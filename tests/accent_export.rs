@@ -0,0 +1,58 @@
+use hebrew_accents::{write_accent_table, AccentTable, TableFormat};
+
+fn export(table: AccentTable, format: TableFormat) -> String {
+    let mut out = Vec::new();
+    write_accent_table(&mut out, table, format).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn test_export_json() {
+    let json = export(AccentTable::Poetry, TableFormat::Json);
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let rows = value.as_array().unwrap();
+    assert_eq!(rows.len(), 23);
+    let oleh = &rows[1];
+    assert_eq!(oleh["english_name"], "Oleh We Yored");
+    assert_eq!(oleh["category"], "Disjunctive");
+    assert_eq!(oleh["rank"], 2);
+    let code_points = oleh["code_points"].as_array().unwrap();
+    assert_eq!(code_points.len(), 2);
+    assert_eq!(code_points[1]["code_point"], "U+05A5");
+    assert_eq!(code_points[1]["position"], "Under");
+    assert_eq!(rows[0]["alternates"], serde_json::Value::Null);
+}
+#[test]
+fn test_export_json_traditions() {
+    let json = export(AccentTable::Prose, TableFormat::Json);
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let traditions = value[1]["code_points"][0]["traditions"].as_array().unwrap();
+    assert_eq!(traditions.len(), 4);
+    assert_eq!(traditions[0]["tradition"], "Ashkenazi");
+    assert_eq!(traditions[0]["english_name"], "Etnachta");
+}
+#[test]
+fn test_export_csv() {
+    let csv = export(AccentTable::Prose, TableFormat::Csv);
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 1 + 28);
+    assert!(lines[0].ends_with(",code_points,hex_values,positions,traditions,comment"));
+    // a field with a comma is quoted
+    assert!(lines[1].starts_with("1,Silluq,סִלּוּק,\"close, cessation\","));
+    // Shalshelet: two code points
+    assert!(lines[4].starts_with("4,Shalshelet,"));
+    assert!(lines[4].contains(",U+0593 + U+05C0,0xd6 0x93 + 0xd7 0x80,Above + After,"));
+    assert!(lines[2].contains("Ashkenazi: Etnachta (אֶתְנַחְתָּ֑א); Sephardi: Atnach"));
+}
+#[test]
+fn test_export_markdown() {
+    let md = export(AccentTable::Pseudo, TableFormat::Markdown);
+    let lines: Vec<&str> = md.lines().collect();
+    assert_eq!(lines.len(), 2 + 3);
+    assert!(lines[0].starts_with("| rank | english_name |"));
+    assert_eq!(
+        lines[1],
+        "|---|---|---|---|---|---|---|---|---|---|---|---|---|---|"
+    );
+    assert!(lines[2].starts_with("| 1 | Soph Pasuq |"));
+}
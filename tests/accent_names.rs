@@ -0,0 +1,76 @@
+use hebrew_accents::{Context, Error, HebrewAccent, PoetryAccent, ProseAccent, PseudoAccent};
+
+#[test]
+fn test_parse_bhs_names() {
+    assert_eq!("Atnach".parse::<ProseAccent>(), Ok(ProseAccent::Atnach));
+    assert_eq!(
+        "zaqeph-qaton".parse::<ProseAccent>(),
+        Ok(ProseAccent::ZaqephQaton)
+    );
+    assert_eq!("Maqqeph".parse::<PseudoAccent>(), Ok(PseudoAccent::Maqqeph));
+    // alternates
+    assert_eq!("Qadma".parse::<ProseAccent>(), Ok(ProseAccent::Azla));
+    assert_eq!(
+        "Qarne Pharah".parse::<ProseAccent>(),
+        Ok(ProseAccent::PazerGadol)
+    );
+}
+#[test]
+fn test_parse_ignoring_case_and_diacritics() {
+    assert_eq!("ATNÁCH".parse::<ProseAccent>(), Ok(ProseAccent::Atnach));
+    assert_eq!("Dechî".parse::<PoetryAccent>(), Ok(PoetryAccent::Dechi));
+    // Hebrew names with and without niqqud
+    assert_eq!("אַתְנָח".parse::<ProseAccent>(), Ok(ProseAccent::Atnach));
+    assert_eq!("אתנח".parse::<ProseAccent>(), Ok(ProseAccent::Atnach));
+    assert_eq!(
+        "עולה ויורד".parse::<PoetryAccent>(),
+        Ok(PoetryAccent::OlehWeYored)
+    );
+}
+#[test]
+fn test_parse_tradition_names() {
+    assert_eq!("Etnachta".parse::<ProseAccent>(), Ok(ProseAccent::Atnach));
+    assert_eq!("Etnacha".parse::<ProseAccent>(), Ok(ProseAccent::Atnach));
+    assert_eq!("אֶתְנַחְתָּא".parse::<ProseAccent>(), Ok(ProseAccent::Atnach));
+}
+#[test]
+fn test_parse_ambiguous() {
+    // the Sephardi name of the Tiphcha code point, used by Tiphcha and Mayela
+    let err = "Tarcha".parse::<ProseAccent>().unwrap_err();
+    assert_eq!(
+        err,
+        Error::AmbiguousAccent {
+            name: "Tarcha".to_string(),
+            candidates: vec![ProseAccent::Tiphcha.into(), ProseAccent::Mayela.into()],
+        }
+    );
+    // a BHS name decides before the tradition names
+    assert_eq!(
+        "Tarcha".parse::<HebrewAccent>(),
+        Ok(PoetryAccent::Tarcha.into())
+    );
+    // prose and poetry
+    assert!(matches!(
+        "Munach".parse::<HebrewAccent>(),
+        Err(Error::AmbiguousAccent { candidates, .. }) if candidates.len() == 2
+    ));
+    assert_eq!(
+        HebrewAccent::parse_in("Munach", Context::Prosaic),
+        Ok(ProseAccent::Munach.into())
+    );
+}
+#[test]
+fn test_parse_unknown() {
+    assert_eq!(
+        "Qadmah Gedolah".parse::<HebrewAccent>(),
+        Err(Error::UnknownAccent("Qadmah Gedolah".to_string()))
+    );
+    assert!(matches!(
+        "".parse::<ProseAccent>(),
+        Err(Error::UnknownAccent(_))
+    ));
+    assert!(matches!(
+        "todo".parse::<PseudoAccent>(),
+        Err(Error::UnknownAccent(_))
+    ));
+}
@@ -0,0 +1,196 @@
+use hebrew_accents::{
+    Context, HebrewAccent, Match, PoetryAccent, ProseAccent, PseudoAccent, SentenceContext,
+};
+
+fn accents_of(sc: &SentenceContext) -> Vec<HebrewAccent> {
+    sc.analyze().into_iter().map(|(accent, _)| accent).collect()
+}
+
+/// The matches of the accent in the analysis are the matches of find_iter()
+fn assert_same_as_find_iter(sc: &SentenceContext, accent: HebrewAccent) {
+    let analyzed: Vec<Match> = sc
+        .analyze()
+        .into_iter()
+        .filter(|(a, _)| *a == accent)
+        .map(|(_, found)| found)
+        .collect();
+    let found: Vec<Match> = sc.find_iter(accent).collect();
+    assert_eq!(analyzed, found, "{accent:?}");
+}
+
+#[test]
+fn test_analyze_prose_gen_1_1() {
+    let sc = SentenceContext::new("בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים אֵ֥ת הַשָּׁמַ֖יִם וְאֵ֥ת הָאָֽרֶץ׃", Context::Prosaic);
+    assert_eq!(
+        accents_of(&sc),
+        vec![
+            ProseAccent::Tiphcha.into(),
+            ProseAccent::Munach.into(),
+            ProseAccent::Atnach.into(),
+            ProseAccent::Merkha.into(),
+            ProseAccent::Tiphcha.into(),
+            ProseAccent::Merkha.into(),
+            ProseAccent::Silluq.into(),
+            PseudoAccent::SophPasuq.into(),
+        ]
+    );
+    // the matches are ordered, the Silluq spans until the Soph Pasuq
+    let analysis = sc.analyze();
+    assert!(analysis.windows(2).all(|w| w[0].1.start() < w[1].1.start()));
+    assert_eq!(&sc.sentence[analysis[2].1.range()], "\u{0591}");
+    assert_eq!(
+        Some(analysis[6].1),
+        sc.find_accent(ProseAccent::Silluq.into())
+    );
+}
+#[test]
+fn test_analyze_prose_munach_legarmeh() {
+    let sc = SentenceContext::new("בּראשׁית בּ֣רא א֣להים ׀ את׃", Context::Prosaic);
+    let analysis = sc.analyze();
+    assert_eq!(analysis.len(), 3);
+    assert_eq!(analysis[0].0, ProseAccent::Munach.into());
+    assert_eq!(analysis[1].0, ProseAccent::Legarmeh.into());
+    // Legarmeh consists of Munach + Paseq, the Paseq is not reported separately
+    assert_eq!(&sc.sentence[analysis[1].1.range()], "\u{05A3}להים \u{05C0}");
+    assert_eq!(analysis[2].0, PseudoAccent::SophPasuq.into());
+}
+#[test]
+fn test_analyze_prose_mayela_meteg() {
+    // Tiphcha in the same word as the Atnach is a Mayela
+    let sc = SentenceContext::new("וַֽיְהִי־עֶ֖רֶב֑ בֹ֖קֶר", Context::Prosaic);
+    assert_eq!(
+        accents_of(&sc),
+        vec![
+            ProseAccent::Meteg.into(),
+            PseudoAccent::Maqqeph.into(),
+            ProseAccent::Mayela.into(),
+            ProseAccent::Atnach.into(),
+            ProseAccent::Tiphcha.into(),
+        ]
+    );
+}
+#[test]
+fn test_analyze_prose_shalshelet() {
+    let sc = SentenceContext::new("בְּהִ֑ים֓׀ אֵ֥ץ׃", Context::Prosaic);
+    assert_eq!(
+        accents_of(&sc),
+        vec![
+            ProseAccent::Atnach.into(),
+            ProseAccent::Shalshelet.into(),
+            ProseAccent::Merkha.into(),
+            PseudoAccent::SophPasuq.into(),
+        ]
+    );
+}
+#[test]
+fn test_analyze_poetry_two_code_points() {
+    // Tsinnorit Merkha and a separate Merkha
+    let sc = SentenceContext::new("בּראשׁית בּר֥א א֘להי֥ם א֥ת", Context::Poetic);
+    assert_eq!(
+        accents_of(&sc),
+        vec![
+            PoetryAccent::Merkha.into(),
+            PoetryAccent::TsinnoritMerkha.into(),
+            PoetryAccent::Merkha.into(),
+        ]
+    );
+    // Revia Qaton followed by Oleh We Yored, Revia Gadol and Revia Mugrash
+    let sc = SentenceContext::new("בּר֗א את השּׁמ֗ים ו֫את הא֥רץ אֱ֜לֹ֗הִים", Context::Poetic);
+    assert_eq!(
+        accents_of(&sc),
+        vec![
+            PoetryAccent::ReviaGadol.into(),
+            PoetryAccent::ReviaQaton.into(),
+            PoetryAccent::OlehWeYored.into(),
+            PoetryAccent::ReviaMugrash.into(),
+        ]
+    );
+}
+#[test]
+fn test_analyze_poetry_legarmeh() {
+    let sc = SentenceContext::new("בּר֤א ׀ את֨ | הא֓רץ ׀ ב֓רא", Context::Poetic);
+    assert_eq!(
+        accents_of(&sc),
+        vec![
+            PoetryAccent::MehuppakhLegarmeh.into(),
+            PoetryAccent::AzlaLegarmeh.into(),
+            PoetryAccent::ShalsheletGadol.into(),
+            PoetryAccent::ShalsheletQetannah.into(),
+        ]
+    );
+}
+#[test]
+fn test_analyze_context_specific_marks() {
+    // Segolta only exists in the prose books
+    let sc = SentenceContext::new("אֶת־הָרָקִיעַ֒", Context::Poetic);
+    assert_eq!(accents_of(&sc), vec![PseudoAccent::Maqqeph.into()]);
+    // Dechi only exists in the poetic books
+    let sc = SentenceContext::new("אַ֭שְׁרֵי", Context::Prosaic);
+    assert!(accents_of(&sc).is_empty());
+}
+#[test]
+fn test_analyze_same_as_find_munach_legarmeh() {
+    let sc = SentenceContext::new("בּראשׁית בּ֣רא א֣להים ׀ את א֣רץ׀ ה֣ים׃", Context::Prosaic);
+    assert_same_as_find_iter(&sc, ProseAccent::Munach.into());
+    assert_same_as_find_iter(&sc, ProseAccent::Legarmeh.into());
+    assert_eq!(sc.find_iter(ProseAccent::Munach.into()).count(), 2);
+    assert_eq!(sc.find_iter(ProseAccent::Legarmeh.into()).count(), 2);
+}
+#[test]
+fn test_analyze_tiphcha_mayela() {
+    let sc = SentenceContext::new(
+        "ויּ֣ר֖א עשׂ֔ו כּ֥י רע֖ות נ֣ות כּ֖נ֑ען בּעינ֖י יצח֥ק א֖בֽיו׃",
+        Context::Prosaic,
+    );
+    assert_same_as_find_iter(&sc, ProseAccent::Mayela.into());
+    assert_eq!(sc.find_iter(ProseAccent::Mayela.into()).count(), 2);
+    // find_iter() finds every Tiphcha mark, the analysis only the ones that
+    // are not a Mayela
+    let tiphcha: Vec<Match> = sc.find_iter(ProseAccent::Tiphcha.into()).collect();
+    let analyzed: Vec<Match> = sc
+        .analyze()
+        .into_iter()
+        .filter(|(accent, _)| *accent == ProseAccent::Tiphcha.into())
+        .map(|(_, found)| found)
+        .collect();
+    assert_eq!(tiphcha.len(), 5);
+    assert_eq!(
+        analyzed.iter().map(|m| m.start()).collect::<Vec<usize>>(),
+        vec![10, 39, 80]
+    );
+    assert!(analyzed.iter().all(|found| tiphcha.contains(found)));
+}
+#[test]
+fn test_analyze_same_as_find_meteg_silluq() {
+    let sc = SentenceContext::new("וַֽיְהִי־עֶ֖רֶב וַֽיְהִי־בֹ֖קֶר י֥וֹם אֶחָֽד׃", Context::Prosaic);
+    assert_same_as_find_iter(&sc, ProseAccent::Meteg.into());
+    assert_same_as_find_iter(&sc, ProseAccent::Silluq.into());
+    assert_eq!(sc.find_iter(ProseAccent::Meteg.into()).count(), 2);
+    assert_eq!(sc.find_iter(ProseAccent::Silluq.into()).count(), 1);
+    // in the poetic books as well
+    let sc = SentenceContext::new("מִזְמ֥וֹר לְדָוִ֑ד יְהֹוָ֥ה רֹ֝עִ֗י לֹ֣א אֶחְסָֽר׃", Context::Poetic);
+    assert_same_as_find_iter(&sc, PoetryAccent::Meteg.into());
+    assert_same_as_find_iter(&sc, PoetryAccent::Silluq.into());
+}
+#[test]
+fn test_analyze_poetry_revia_mugrash_ps_23_1() {
+    // the Revia Mugrash is written with a Geresh Muqdam
+    let sc = SentenceContext::new("מִזְמ֥וֹר לְדָוִ֑ד יְהֹוָ֥ה רֹ֝עִ֗י לֹ֣א אֶחְסָֽר׃", Context::Poetic);
+    let analysis = sc.analyze();
+    let mugrash: Vec<&Match> = analysis
+        .iter()
+        .filter(|(accent, _)| *accent == PoetryAccent::ReviaMugrash.into())
+        .map(|(_, found)| found)
+        .collect();
+    assert_eq!(mugrash.len(), 1);
+    assert_eq!(
+        Some(*mugrash[0]),
+        sc.find_accent(PoetryAccent::ReviaMugrash.into())
+    );
+    assert!(!accents_of(&sc).contains(&PoetryAccent::ReviaGadol.into()));
+}
+#[test]
+fn test_try_analyze() {
+    let sc = SentenceContext::new("בּראשׁית בּ֣רא א֣להים׀ את", Context::Prosaic);
+    assert_eq!(sc.try_analyze(), Ok(sc.analyze()));
+}
@@ -0,0 +1,68 @@
+use std::ffi::OsStr;
+
+use hebrew_accents::{
+    ansi_legend, use_color_with, AnsiScheme, Context, ProseAccent, SentenceContext,
+};
+
+#[test]
+fn test_to_ansi_strength() {
+    let sc = SentenceContext::new("אֱלֹהִ֑ים אֵ֥ת הַשָּׁמַ֖יִם וְאֵ֥ת הָאָֽרֶץ׃", Context::Prosaic);
+    let text = sc.to_ansi(AnsiScheme::Strength, true);
+    assert!(text.starts_with("\x1b[31mאֱלֹהִ֑ים\x1b[0m \x1b[34mאֵ֥ת\x1b[0m"));
+    assert!(text.contains("\x1b[33mהַשָּׁמַ֖יִם\x1b[0m"));
+    // the Sof Pasuq is not part of the word
+    assert!(text.ends_with("\x1b[1;31mהָאָֽרֶץ\x1b[0m׃"));
+}
+#[test]
+fn test_to_ansi_without_color() {
+    let sc = SentenceContext::new("בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים", Context::Prosaic);
+    assert_eq!(sc.to_ansi(AnsiScheme::Strength, false), sc.sentence);
+}
+#[test]
+fn test_to_ansi_accent() {
+    let sc = SentenceContext::new("אֱלֹהִ֑ים אֵ֥ת הַשָּׁמַ֖יִם וְאֵ֥ת הָאָֽרֶץ׃", Context::Prosaic);
+    let text = sc.to_ansi_accent(ProseAccent::Merkha.into(), AnsiScheme::Category, true);
+    assert_eq!(
+        text,
+        "אֱלֹהִ֑ים \x1b[34mאֵ֥ת\x1b[0m הַשָּׁמַ֖יִם \x1b[34mוְאֵ֥ת\x1b[0m הָאָֽרֶץ׃"
+    );
+    assert_eq!(
+        sc.to_ansi_accent(ProseAccent::Merkha.into(), AnsiScheme::Category, false),
+        sc.sentence
+    );
+}
+#[test]
+fn test_ansi_legend() {
+    let legend = ansi_legend(AnsiScheme::Strength, Context::Poetic, false);
+    let lines: Vec<&str> = legend.lines().collect();
+    assert_eq!(lines[0], "level 0: Silluq");
+    assert_eq!(lines[1], "level 1: Oleh We Yored");
+    assert_eq!(lines[2], "level 2: Atnach");
+    assert!(lines
+        .iter()
+        .any(|line| line.starts_with("conjunctive: Munach, Merkha")));
+    let legend = ansi_legend(AnsiScheme::Category, Context::Prosaic, true);
+    assert!(legend.starts_with("\x1b[31mdisjunctive: Silluq"));
+}
+#[test]
+fn test_use_color_no_color() {
+    assert!(!use_color_with(Some(OsStr::new("1")), true));
+    // an empty NO_COLOR is ignored
+    assert!(use_color_with(Some(OsStr::new("")), true));
+    assert!(use_color_with(None, true));
+    assert!(!use_color_with(None, false));
+}
+#[test]
+fn test_write_ansi() {
+    let sc = SentenceContext::new("בָּרָ֣א אֱלֹהִ֑ים", Context::Prosaic);
+    let mut out = Vec::new();
+    sc.write_ansi(&mut out, AnsiScheme::Category, true).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        format!(
+            "{}\n{}",
+            sc.to_ansi(AnsiScheme::Category, true),
+            ansi_legend(AnsiScheme::Category, Context::Prosaic, true)
+        )
+    );
+}
@@ -0,0 +1,111 @@
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+const VERSES: &str = "\
+בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים אֵ֥ת הַשָּׁמַ֖יִם וְאֵ֥ת הָאָֽרֶץ׃
+וְהָאָ֗רֶץ הָיְתָ֥ה תֹ֙הוּ֙ וָבֹ֔הוּ
+וַיֹּ֥אמֶר אֱלֹהִ֖ים יְהִ֣י א֑וֹר וַֽיְהִי־אֽוֹר׃
+";
+
+fn run(args: &[&str]) -> Output {
+    run_with(args, VERSES)
+}
+
+fn run_with(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_hebrew-accents"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    // subcommands that do not read stdin may exit before it is written
+    let _ = child.stdin.take().unwrap().write_all(stdin.as_bytes());
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn test_cli_grep() {
+    let output = run(&["grep", "Etnachta", "--color", "never"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("(stdin):1: בְּרֵאשִׁ֖ית"));
+    assert!(lines[1].starts_with("(stdin):3: "));
+    // with context and color
+    let output = run(&["grep", "Revia", "-C", "1", "--color", "always"]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].starts_with("(stdin)-1- "));
+    assert!(lines[1].starts_with("(stdin):2: \x1b[31mוְהָאָ֗רֶץ\x1b[0m"));
+}
+#[test]
+fn test_cli_grep_no_match_and_unknown() {
+    assert_eq!(run(&["grep", "Segolta"]).status.code(), Some(1));
+    let output = run(&["grep", "Foo"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("unknown accent name: Foo"));
+}
+#[test]
+fn test_cli_analyze_and_stats() {
+    let stdout = String::from_utf8(run(&["analyze"]).stdout).unwrap();
+    assert!(stdout.starts_with("(stdin):1\n  בְּרֵאשִׁ֖ית\tTiphcha\n"));
+    // the words of a Maqqeph group are listed one by one
+    assert!(stdout.contains("  וַֽיְהִי\tMeteg\n  אֽוֹר\tSilluq\n"));
+    let stdout = String::from_utf8(run(&["stats"]).stdout).unwrap();
+    assert!(stdout
+        .lines()
+        .any(|line| line.starts_with("Atnach") && line.contains(" 2 ")));
+    let stdout = String::from_utf8(run(&["stats", "--format", "csv"]).stdout).unwrap();
+    assert!(stdout.starts_with("statistic,key,position,count\n"));
+    assert!(stdout.contains("\nposition,prose:Silluq,0,"));
+}
+#[test]
+fn test_cli_backtrack_limit_exceeded() {
+    // the error of the long verse is reported, the other verses are processed
+    let verses = format!("{}{}\n", VERSES, "א ".repeat(1_000_000));
+    let output = run_with(&["grep", "Munach", "--color", "never"], &verses);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(String::from_utf8(output.stdout).unwrap().lines().count(), 2);
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("hebrew-accents: (stdin):4: "));
+    for args in [&["analyze"][..], &["stats"][..]] {
+        let output = run_with(args, &verses);
+        assert!(output.status.success());
+        assert!(String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("hebrew-accents: (stdin):4: "));
+    }
+}
+#[test]
+fn test_cli_tables() {
+    let stdout =
+        String::from_utf8(run(&["tables", "--table", "poetry", "--format", "csv"]).stdout).unwrap();
+    assert_eq!(stdout.lines().count(), 1 + 23);
+    assert!(stdout
+        .lines()
+        .nth(2)
+        .unwrap()
+        .starts_with("2,Oleh We Yored,"));
+}
+#[test]
+fn test_cli_tsv() {
+    let tsv = "Gen\t1\t1\tבְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים\nPs\t23\t1\tמִזְמ֥וֹר לְדָוִ֑ד\nFoo\t1\t1\tא\n";
+    let output = run_with(&["grep", "Atnach", "--tsv"], tsv);
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.lines().count(), 2);
+    assert!(stdout.contains("(stdin):2: מִזְמ֥וֹר"));
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("(stdin): line 3: invalid Bible reference: Foo"));
+    let stdout = String::from_utf8(run_with(&["analyze", "--tsv"], tsv).stdout).unwrap();
+    assert!(stdout.starts_with("(stdin):1 Gen 1:1\n"));
+}
@@ -0,0 +1,86 @@
+use hebrew_accents::{
+    Book, Context, CorpusFormat, CorpusReader, CorpusVerse, Error, ProseAccent, Reference,
+};
+
+fn read(text: &[u8], format: CorpusFormat) -> Vec<Result<CorpusVerse, Error>> {
+    CorpusReader::new(text, format).collect()
+}
+
+#[test]
+fn test_corpus_tsv() {
+    let tsv = "\u{FEFF}Gen\t1\t1\tבְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים\r\n\
+               \n\
+               # Job 3:1 is prose, 3:2 poetry\n\
+               Job\t3\t1\tאַחֲרֵי־כֵ֗ן\n\
+               Job\t3\t2\tוַיַּ֥עַן אִיּ֗וֹב וַיֹּאמַֽר׃\n";
+    let verses: Vec<CorpusVerse> = read(tsv.as_bytes(), CorpusFormat::Tsv)
+        .into_iter()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(verses.len(), 3);
+    assert_eq!(verses[0].line, 1);
+    assert_eq!(
+        verses[0].reference,
+        Some(Reference::new(Book::Genesis, 1, 1).unwrap())
+    );
+    assert_eq!(verses[0].sc.sentence, "בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים");
+    assert!(verses[0].sc.contains_accent(ProseAccent::Atnach.into()));
+    assert_eq!(verses[1].line, 4);
+    assert_eq!(verses[1].sc.ctx, Context::Prosaic);
+    assert_eq!(verses[2].sc.ctx, Context::Poetic);
+}
+#[test]
+fn test_corpus_tsv_errors() {
+    let tsv = "Gen\t1\t1\tא\nGen 1 2 ב\nFoo\t1\t1\tג\nGen\tI\t1\tד\nGen\t1\t\tה\nGen\t51\t1\tו\nGen\t1\t7\t \nGen\t1\t8\tז\n";
+    let results = read(tsv.as_bytes(), CorpusFormat::Tsv);
+    assert_eq!(results.len(), 8);
+    assert!(results[0].is_ok());
+    for (idx, result) in results.iter().enumerate().take(7).skip(1) {
+        match result {
+            Err(Error::CorpusLine { line, .. }) => assert_eq!(*line, idx + 1),
+            other => panic!("line {}: {:?}", idx + 1, other),
+        }
+    }
+    let Err(err) = &results[3] else { panic!() };
+    assert_eq!(err.to_string(), "line 4: invalid chapter: I");
+    // reading continues after a malformed line
+    assert_eq!(results[7].as_ref().unwrap().line, 8);
+}
+#[test]
+fn test_corpus_plain() {
+    let plain = "וַיֹּ֥אמֶר אֱלֹהִ֖ים יְהִ֣י א֑וֹר וַֽיְהִי־אֽוֹר׃ פ\nבְּרֵאשִׁ֖ית בָּרָ֣א׃ ס\n";
+    let verses: Vec<CorpusVerse> = read(plain.as_bytes(), CorpusFormat::Plain)
+        .into_iter()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(verses.len(), 2);
+    assert_eq!(verses[0].reference, None);
+    assert_eq!(verses[0].sc.ctx, Context::Prosaic);
+    assert!(verses[0].sc.contains_accent(ProseAccent::Silluq.into()));
+    assert!(verses[1].sc.sentence.ends_with("׃ ס"));
+}
+#[test]
+fn test_corpus_options() {
+    let tsv = "book\tchapter\tverse\ttext\nGen\t1\t1\tא\n";
+    let verses: Vec<_> = CorpusReader::new(tsv.as_bytes(), CorpusFormat::Tsv)
+        .header(true)
+        .context(Context::Poetic)
+        .collect();
+    assert_eq!(verses.len(), 1);
+    let verse = verses[0].as_ref().unwrap();
+    assert_eq!(verse.line, 2);
+    assert_eq!(verse.sc.ctx, Context::Poetic);
+}
+#[test]
+fn test_corpus_invalid_utf8() {
+    let results = read(b"\xff\xfe\n\xd7\x90\n", CorpusFormat::Plain);
+    assert!(matches!(results[0], Err(Error::CorpusLine { line: 1, .. })));
+    assert_eq!(results[1].as_ref().unwrap().sc.sentence, "א");
+}
+#[test]
+fn test_corpus_open() {
+    assert!(matches!(
+        CorpusReader::open("does/not/exist.tsv", CorpusFormat::Tsv),
+        Err(Error::Corpus(_))
+    ));
+}
@@ -2,12 +2,14 @@ use hebrew_accents::{Context, Match, PoetryAccent, ProseAccent, PseudoAccent, Se
 
 #[test]
 fn test_find_prose_poetry_silluq() {
+    // the match is the Silluq mark only
     // ProseAccent, with Soph Pasuq and Meteg, no Pey or Samech
     let sc = SentenceContext::new("הִי אֽוֹר׃", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO::Outermatch",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Silluq.into(),
         start: 9,
-        end: 19,
+        end: 11,
     };
     assert_eq!(sc.find_accent(ProseAccent::Silluq.into()), Some(expected));
     // ProseAccent, with Soph Pasuq, no Pey or Samech
@@ -16,9 +18,10 @@ fn test_find_prose_poetry_silluq() {
         Context::Prosaic,
     );
     let expected = Match {
-        haystack: "TODO::Outermatch",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Silluq.into(),
         start: 159,
-        end: 168,
+        end: 161,
     };
     assert_eq!(sc.find_accent(ProseAccent::Silluq.into()), Some(expected));
     // ProseAccent, no Soph Paseq, with Pey
@@ -27,9 +30,10 @@ fn test_find_prose_poetry_silluq() {
         Context::Poetic,
     );
     let expected = Match {
-        haystack: "TODO::Outermatch",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::Silluq.into(),
         start: 165,
-        end: 175,
+        end: 167,
     };
     assert_eq!(sc.find_accent(PoetryAccent::Silluq.into()), Some(expected));
     // PoetryAccent with Soph Paseq and Peh
@@ -38,9 +42,10 @@ fn test_find_prose_poetry_silluq() {
         Context::Poetic,
     );
     let expected = Match {
-        haystack: "TODO::Outermatch",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::Silluq.into(),
         start: 159,
-        end: 171,
+        end: 161,
     };
     assert_eq!(sc.find_accent(PoetryAccent::Silluq.into()), Some(expected));
     // Meteg not in the last word of the sentence
@@ -58,7 +63,8 @@ fn test_find_prose_poetry_atnach() {
     // Atnach present
     let sc = SentenceContext::new("בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים אֵ֥ת הַשָּׁמַ֖יִם וְאֵ֥ת הָאָֽרֶץ׃", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO: insert single character",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Atnach.into(),
         start: 52,
         end: 54,
     };
@@ -76,7 +82,8 @@ fn test_find_prose_segolta() {
         Context::Prosaic,
     );
     let expected = Match {
-        haystack: "TODO: insert single character",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Segolta.into(),
         start: 67,
         end: 69,
     };
@@ -92,7 +99,8 @@ fn test_find_prose_shalshelet() {
     // Shalshelet, with Paseq - no space
     let sc = SentenceContext::new("בְּהִ֑ים֓׀ אֵ֥ץ׃", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO::2CodePoints",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Shalshelet.into(),
         start: 16,
         end: 20,
     };
@@ -103,7 +111,8 @@ fn test_find_prose_shalshelet() {
     // Shalshelet, with Paseq + one space
     let sc = SentenceContext::new("בְּהִ֑ים֓ ׀ אֵ֥ץ׃", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO::2CodePoints",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Shalshelet.into(),
         start: 16,
         end: 21,
     };
@@ -115,7 +124,8 @@ fn test_find_prose_shalshelet() {
     // Shalshelet, with Vertical Bar - no space
     let sc = SentenceContext::new("בְּהִ֑ים֓| אֵ֥ץ׃", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO::2CodePoints",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Shalshelet.into(),
         start: 16,
         end: 19,
     };
@@ -126,7 +136,8 @@ fn test_find_prose_shalshelet() {
     // Shalshelet, with Vertical Bar + one space
     let sc = SentenceContext::new("בְּהִ֑ים֓ | אֵ֥ץ׃", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO::2CodePoints",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Shalshelet.into(),
         start: 16,
         end: 20,
     };
@@ -142,7 +153,8 @@ fn test_find_prose_shalshelet() {
 fn test_find_prose_zaqeph_qaton() {
     let sc = SentenceContext::new("בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים אֵ֔ת הַשָּׁמַ֖יִם וְאֵ֥ת הָאָֽרֶץ׃", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO: insert single character",
+        haystack: &sc.sentence,
+        accent: ProseAccent::ZaqephQaton.into(),
         start: 63,
         end: 65,
     };
@@ -157,7 +169,8 @@ fn test_find_prose_zaqeph_qaton() {
 fn test_find_prose_zaqeph_gadol() {
     let sc = SentenceContext::new("בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹ֕הִ֑ים אֵ֥ת הַשָּׁמַ֖יִם וְאֵ֥ת הָאָֽרֶץ׃", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO: insert single character",
+        haystack: &sc.sentence,
+        accent: ProseAccent::ZaqephGadol.into(),
         start: 48,
         end: 50,
     };
@@ -172,7 +185,8 @@ fn test_find_prose_zaqeph_gadol() {
 fn test_find_prose_revia() {
     let sc = SentenceContext::new("אלהים֮ את־הרקיע֒ ויּבדּ֗ל בּ֤ין", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO: insert single character",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Revia.into(),
         start: 44,
         end: 46,
     };
@@ -187,14 +201,16 @@ fn test_find_prose_tiphcha() {
         Context::Prosaic,
     );
     let expected = Match {
-        haystack: "TODO: insert single character",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Tiphcha.into(),
         start: 10,
         end: 12,
     };
     assert_eq!(sc.find_accent(ProseAccent::Tiphcha.into()), Some(expected));
     let sc = SentenceContext::new("אתך ר֖בך֑ אתך ו֖המֽים׃", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO: insert single character",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Tiphcha.into(),
         start: 9,
         end: 11,
     };
@@ -204,7 +220,8 @@ fn test_find_prose_tiphcha() {
 fn test_find_prose_zarqa() {
     let sc = SentenceContext::new("בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים אֵ֥ת הַשָּׁמַ֖יִם וְאֵ֥ת הָאָֽרֶ֘ץ׃", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO: insert single character",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Zarqa.into(),
         start: 120,
         end: 122,
     };
@@ -216,7 +233,8 @@ fn test_find_prose_zarqa() {
 fn test_find_prose_pashta() {
     let sc = SentenceContext::new("בְּרֵאשִׁ֖ית בָּרָ֣א אֱ֙לֹהִ֑ים אֵ֥ת הַשָּׁמַ֖יִם וְאֵ֥ת הָאָֽרֶץ׃", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO: insert single character",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Pashta.into(),
         start: 44,
         end: 46,
     };
@@ -228,7 +246,8 @@ fn test_find_prose_pashta() {
 fn test_find_prose_yetiv() {
     let sc = SentenceContext::new("אֽת־יעקב֒ ושׁלּ֤ח א֚תו֙", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO: insert single character",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Yetiv.into(),
         start: 36,
         end: 38,
     };
@@ -240,7 +259,8 @@ fn test_find_prose_yetiv() {
 fn test_find_prose_tevir() {
     let sc = SentenceContext::new("בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים אֵ֥ת הַשָּׁמצ֛יִם ד֛דד הָאָֽרֶץ׃", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO: insert single character",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Tevir.into(),
         start: 84,
         end: 86,
     };
@@ -252,7 +272,8 @@ fn test_find_prose_tevir() {
 fn test_find_prose_geresh() {
     let sc = SentenceContext::new("בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים אֵ֥ת הַשּׁ֜מַ֖יִם וְאֵ֥ת הָאָֽרֶץ׃", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO: insert single character",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Geresh.into(),
         start: 78,
         end: 80,
     };
@@ -264,7 +285,8 @@ fn test_find_prose_geresh() {
 fn test_find_prose_gershayim() {
     let sc = SentenceContext::new("בְּרֵאשִׁ֞ית בָּרָ֣א אֱלֹהִ֑ים אֵ֥ת הַשָּׁמַ֖יִם וְאֵ֥ת הָאָֽרֶץ׃", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO: insert single character",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Gershayim.into(),
         start: 18,
         end: 20,
     };
@@ -279,7 +301,8 @@ fn test_find_prose_gershayim() {
 fn test_find_prose_pazer() {
     let sc = SentenceContext::new("בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים אֵ֥ת הַשָּׁמַ֖יִם וְא֡ת הָאָֽרֶץ׃", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO: insert single character",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Pazer.into(),
         start: 99,
         end: 101,
     };
@@ -291,7 +314,8 @@ fn test_find_prose_pazer() {
 fn test_find_prose_pazer_gadol() {
     let sc = SentenceContext::new("בְּרֵא֟שִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים אֵ֥ת הַשָּׁמַ֖יִם וְאֵ֥ת הָאָֽרֶץ׃", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO: insert single character",
+        haystack: &sc.sentence,
+        accent: ProseAccent::PazerGadol.into(),
         start: 12,
         end: 14,
     };
@@ -306,7 +330,8 @@ fn test_find_prose_pazer_gadol() {
 fn test_find_prose_telisha_gadolah() {
     let sc = SentenceContext::new("בְּרֵא֠ית בָּרָ֣א אֱלֹהִ֑ים אֵ֥ת הַשָּׁמַ֖יִם וְאֵ֥ת הָאָֽרֶץ׃", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO: insert single character",
+        haystack: &sc.sentence,
+        accent: ProseAccent::TelishaGedolah.into(),
         start: 12,
         end: 14,
     };
@@ -322,7 +347,8 @@ fn test_find_prose_legarmeh() {
     // Legarmeh, with Paseq
     let sc = SentenceContext::new("בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֣ים׀  אֵ֥ת הַשָּׁמַ֖יִם וְאֵ֥ת הָאָֽרֶץ׃", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO::Inner Match",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Legarmeh.into(),
         start: 52,
         end: 60,
     };
@@ -330,7 +356,8 @@ fn test_find_prose_legarmeh() {
                                                                               // Legarmeh with a space + Paseq
     let sc = SentenceContext::new("בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֣ים ׀  אֵ֥ת הַשָּׁמַ֖יִם וְאֵ֥ת הָאָֽרֶץ׃", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO::Inner Match",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Legarmeh.into(),
         start: 52,
         end: 61,
     };
@@ -341,7 +368,8 @@ fn test_find_prose_legarmeh() {
     // Legarmeh, with Vertical Bar
     let sc = SentenceContext::new("בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֣ים|  אֵ֥ת הַשָּׁמַ֖יִם וְאֵ֥ת הָאָֽרֶץ׃", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO::Inner Match",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Legarmeh.into(),
         start: 52,
         end: 59,
     };
@@ -349,7 +377,8 @@ fn test_find_prose_legarmeh() {
                                                                               // Legarmeh, with space + Vertical Bar
     let sc = SentenceContext::new("בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֣ים |  אֵ֥ת הַשָּׁמַ֖יִם וְאֵ֥ת הָאָֽרֶץ׃", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO::Inner Match",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Legarmeh.into(),
         start: 52,
         end: 60,
     };
@@ -367,7 +396,8 @@ fn test_find_prose_munnach() {
     // Single Munach
     let sc = SentenceContext::new("בּראשׁית בּרא א֣להים את השּׁמים ואת הארץ׃", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO::Outer Match",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Munach.into(),
         start: 28,
         end: 30,
     };
@@ -389,7 +419,8 @@ fn test_find_prose_munnach() {
 fn test_find_prose_mahpakh() {
     let sc = SentenceContext::new("בּאשׁ֤ית בּא אלֹהִים אֵת הַשָּׁמַיִם וְאת האץ׃", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO: insert single character",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Mahpakh.into(),
         start: 10,
         end: 12,
     };
@@ -401,7 +432,8 @@ fn test_find_prose_mahpakh() {
 fn test_find_prose_merkha() {
     let sc = SentenceContext::new("מזמ֥ור לדו֑ד יהו֥ה ר֝ע֗י ל֣א אחסֽר׃", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO: insert single character",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Merkha.into(),
         start: 6,
         end: 8,
     };
@@ -413,7 +445,8 @@ fn test_find_prose_merkha() {
 fn test_find_prose_merkha_kephulah() {
     let sc = SentenceContext::new("בְּרֵאשִׁ֦ית בָּרָ֣א אֱלֹהִ֑ים אֵ֥ת הַשָּׁמַ֖יִם וְאֵ֥ת הָאָרֶץ׃", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO: insert single character",
+        haystack: &sc.sentence,
+        accent: ProseAccent::MerkhaKephulah.into(),
         start: 18,
         end: 20,
     };
@@ -428,7 +461,8 @@ fn test_find_prose_merkha_kephulah() {
 fn test_find_prose_darga() {
     let sc = SentenceContext::new("בּראשׁית בּרא אלהים את השּׁמים֧ ואת הארץ׃", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO: insert single character",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Darga.into(),
         start: 56,
         end: 58,
     };
@@ -440,7 +474,8 @@ fn test_find_prose_darga() {
 fn test_find_prose_azla() {
     let sc = SentenceContext::new("בּראשׁית בּרא אלהים א֨ת השּׁמים ואת הארץ׃", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO: insert single character",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Azla.into(),
         start: 39,
         end: 41,
     };
@@ -452,7 +487,8 @@ fn test_find_prose_azla() {
 fn test_find_prose_telisha_qetannah() {
     let sc = SentenceContext::new("בּראשׁית בּרא אלהים את השּׁמים וא֩ת הארץ׃", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO: insert single character",
+        haystack: &sc.sentence,
+        accent: ProseAccent::TelishaQetannah.into(),
         start: 61,
         end: 63,
     };
@@ -467,7 +503,8 @@ fn test_find_prose_telisha_qetannah() {
 fn test_find_prose_galgal() {
     let sc = SentenceContext::new("בּראשׁית בּר֪א אלהים את השּׁמים ואת הארץ׃", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO: insert single character",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Galgal.into(),
         start: 23,
         end: 25,
     };
@@ -477,28 +514,32 @@ fn test_find_prose_galgal() {
 }
 #[test]
 fn test_find_prose_meayla() {
+    // the match is the Tiphcha mark only
     // Tiphcha followed by Atnach
     let sc = SentenceContext::new("וְבְּרֵאשִׁית בָּרָא אֱלֹ֖הִ֑ים אֵת הַשָּׁמַיִם וְאֵת הָאָֽרֶץ", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO::Outer Match",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Mayela.into(),
         start: 48,
-        end: 56,
+        end: 50,
     };
     assert_eq!(sc.find_accent(ProseAccent::Mayela.into()), Some(expected));
     // Tiphcha followed by Atnach, two words connected with a Maqqeph
     let sc = SentenceContext::new("ויּ֖צא־נ֑ח וּבנ֛יו ואשׁתּ֥ו וּנשֽׁי־בנ֖יו אתּֽו׃", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO::Outer Match",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Mayela.into(),
         start: 6,
-        end: 18,
+        end: 8,
     };
     assert_eq!(sc.find_accent(ProseAccent::Mayela.into()), Some(expected));
     // Tiphcha followed by silluq
     let sc = SentenceContext::new("וְבְּרֵאשִׁית בָּרָא אֱלֹהִ֑ים אֵת הַשָּׁמַיִם וְאֵת הָ֖אָֽרֶץ", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO::Outer Match",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Mayela.into(),
         start: 104,
-        end: 114,
+        end: 106,
     };
     assert_eq!(sc.find_accent(ProseAccent::Mayela.into()), Some(expected));
     // only Tiphcha
@@ -513,7 +554,8 @@ fn test_find_prose_poetry_meteg() {
     // Meteg and Siluq, separated by a Maqqeph
     let sc = SentenceContext::new("ויּ֥אמר אלה֖ים יה֣י א֑ור וֽיהי־אֽור׃", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO::Outer Match",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Meteg.into(),
         start: 48,
         end: 50,
     };
@@ -524,7 +566,8 @@ fn test_find_prose_poetry_meteg() {
         Context::Poetic,
     );
     let expected = Match {
-        haystack: "TODO::Outer Match",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::Meteg.into(),
         start: 30,
         end: 32,
     };
@@ -532,7 +575,8 @@ fn test_find_prose_poetry_meteg() {
     // Only Meteg, no Silluq
     let sc = SentenceContext::new("וֽיהי־ב֖קר י֥ום שׁני׃ פ", Context::Prosaic);
     let expected = Match {
-        haystack: "TODO::Outer Match",
+        haystack: &sc.sentence,
+        accent: ProseAccent::Meteg.into(),
         start: 2,
         end: 4,
     };
@@ -540,7 +584,8 @@ fn test_find_prose_poetry_meteg() {
     // Two Meteg's, no Silluq
     let sc = SentenceContext::new("ום וֽיהי־ע֥רב וֽיהי־ב֖קר י֥ום שׁני׃ פ", Context::Poetic);
     let expected = Match {
-        haystack: "TODO::Outer Match",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::Meteg.into(),
         start: 7,
         end: 9,
     };
@@ -554,7 +599,8 @@ fn test_find_prose_poetry_maqqeph() {
     // One Maqqeph
     let sc = SentenceContext::new("ויּ֥אמר אלה֖ים יה֣י א֑ור וֽיהי־אֽור׃", Context::Poetic);
     let expected = Match {
-        haystack: "TODO: insert single character",
+        haystack: &sc.sentence,
+        accent: PseudoAccent::Maqqeph.into(),
         start: 56,
         end: 58,
     };
@@ -565,7 +611,8 @@ fn test_find_prose_poetry_maqqeph() {
     // One Maqqeph
     let sc = SentenceContext::new("ויּ֥אמר אלה֖ים יה֣י א֑ור וֽיהי־אֽור׃", Context::Poetic);
     let expected = Match {
-        haystack: "TODO: insert single character",
+        haystack: &sc.sentence,
+        accent: PseudoAccent::Maqqeph.into(),
         start: 56,
         end: 58,
     };
@@ -579,7 +626,8 @@ fn test_find_poetry_oleh_we_yored() {
     // OlehWeYored, one word
     let sc = SentenceContext::new("בְּרֵעַֽל־פַּלְגֵ֫ימָ֥יִ", Context::Poetic);
     let expected = Match {
-        haystack: "TODO::Outer Match",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::OlehWeYored.into(),
         start: 34,
         end: 44,
     };
@@ -593,7 +641,8 @@ fn test_find_poetry_oleh_we_yored() {
     // OlehWeYored, two words
     let sc = SentenceContext::new("ועַֽל־פַּלְגֵ֫י מָ֥יִם וְעָלֵ֥הוּ ׃", Context::Poetic);
     let expected = Match {
-        haystack: "TODO::Outer Match",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::OlehWeYored.into(),
         start: 26,
         end: 37,
     };
@@ -606,6 +655,22 @@ fn test_find_poetry_oleh_we_yored() {
     assert_eq!(sc.find_accent(PoetryAccent::OlehWeYored.into()), None);
 }
 #[test]
+fn test_find_poetry_oleh_we_yored_nearest_yored() {
+    // the nearest Yored completes the OlehWeYored
+    // (the baseline regex was greedy and ended at the Merkha of the next word: 2..17)
+    let sc = SentenceContext::new("ו֫א֥ת הא֥רץ", Context::Poetic);
+    let expected = Match {
+        haystack: &sc.sentence,
+        accent: PoetryAccent::OlehWeYored.into(),
+        start: 2,
+        end: 8,
+    };
+    assert_eq!(
+        sc.find_accent(PoetryAccent::OlehWeYored.into()),
+        Some(expected)
+    );
+}
+#[test]
 fn test_find_poetry_revia_gadol() {
     // No Revia at all
     let sc = SentenceContext::new("בּראשׁית בּרא אלהים את השּׁמים ואת הארץ׃", Context::Poetic);
@@ -613,9 +678,10 @@ fn test_find_poetry_revia_gadol() {
     // Two Revia's
     let sc = SentenceContext::new("בּר֗אשׁית בּרא אלהים את השּׁ֗מים ואת הארץ׃", Context::Poetic);
     let expected = Match {
-        haystack: "\u{597}",
-        start: 3,
-        end: 5,
+        haystack: &sc.sentence,
+        accent: PoetryAccent::ReviaGadol.into(),
+        start: 6,
+        end: 8,
     };
     assert_eq!(
         sc.find_accent(PoetryAccent::ReviaGadol.into()),
@@ -630,9 +696,10 @@ fn test_find_poetry_revia_gadol() {
     // Revia followed by 'Oleh We Yored' (3 words)
     let sc = SentenceContext::new("בּר֗אשׁית בּ֫רא אלהים א֥ת השּׁמים ואת הארץ׃", Context::Poetic);
     let expected = Match {
-        haystack: "\u{597}",
-        start: 3,
-        end: 5,
+        haystack: &sc.sentence,
+        accent: PoetryAccent::ReviaGadol.into(),
+        start: 6,
+        end: 8,
     };
     assert_eq!(
         sc.find_accent(PoetryAccent::ReviaGadol.into()),
@@ -641,9 +708,10 @@ fn test_find_poetry_revia_gadol() {
     // Revia not directly followed by Oleh We Yored (1 word)
     let sc = SentenceContext::new("בּר֗אשׁית בּרא אלה֫י֥ם את השּׁמים ואת הארץ׃", Context::Poetic);
     let expected = Match {
-        haystack: "\u{597}",
-        start: 3,
-        end: 5,
+        haystack: &sc.sentence,
+        accent: PoetryAccent::ReviaGadol.into(),
+        start: 6,
+        end: 8,
     };
     assert_eq!(
         sc.find_accent(PoetryAccent::ReviaGadol.into()),
@@ -655,7 +723,8 @@ fn test_find_poetry_revia_mugrash() {
     // Revia and Geresh (Ps 32:3)
     let sc = SentenceContext::new("בְּ֝שַׁאֲגָתִ֗י", Context::Poetic);
     let expected = Match {
-        haystack: "TODO::2CodePoints",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::ReviaMugrash.into(),
         start: 6,
         end: 28,
     };
@@ -666,7 +735,8 @@ fn test_find_poetry_revia_mugrash() {
     // Revia and Geresh (Ps 110:6) - accent on a single character
     let sc = SentenceContext::new("יָדִ֣ין בַּ֭גּוֹיִם מָלֵ֣א גְוִיּ֑וֹת מָ֥חַץ רֹ֝֗אשׁ עַל־אֶ֥רֶץ רַבָּֽה׃", Context::Poetic);
     let expected = Match {
-        haystack: "TODO::2CodePoints",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::ReviaMugrash.into(),
         start: 89,
         end: 93,
     };
@@ -692,7 +762,8 @@ fn test_find_poetry_shalshelet_gadol() {
     // Shalshelet Gadol, with Paseq - no space
     let sc = SentenceContext::new("בְּהִ֑ים֓׀ אֵ֥ץ׃", Context::Poetic);
     let expected = Match {
-        haystack: "TODO::2CodePoints",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::ShalsheletGadol.into(),
         start: 16,
         end: 20,
     };
@@ -703,7 +774,8 @@ fn test_find_poetry_shalshelet_gadol() {
     // Shalshelet Gadol, with Paseq + one space
     let sc = SentenceContext::new("בְּהִ֑ים֓ ׀ אֵ֥ץ׃", Context::Poetic);
     let expected = Match {
-        haystack: "TODO::2CodePoints",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::ShalsheletGadol.into(),
         start: 16,
         end: 21,
     };
@@ -714,7 +786,8 @@ fn test_find_poetry_shalshelet_gadol() {
     // Shalshelet Gadol, with Vertical Bar - no space
     let sc = SentenceContext::new("בְּהִ֑ים֓| אֵ֥ץ׃", Context::Poetic);
     let expected = Match {
-        haystack: "TODO::2CodePoints",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::ShalsheletGadol.into(),
         start: 16,
         end: 19,
     };
@@ -725,7 +798,8 @@ fn test_find_poetry_shalshelet_gadol() {
     // Shalshelet Gadol, with Vertical Bar + one space
     let sc = SentenceContext::new("בְּהִ֑ים֓ | אֵ֥ץ׃", Context::Poetic);
     let expected = Match {
-        haystack: "TODO::2CodePoints",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::ShalsheletGadol.into(),
         start: 16,
         end: 20,
     };
@@ -741,7 +815,8 @@ fn test_find_poetry_shalshelet_gadol() {
 fn test_find_poetry_tsinnor() {
     let sc = SentenceContext::new("את־אבר֮הם", Context::Poetic);
     let expected = Match {
-        haystack: "TODO: insert single character",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::Tsinnor.into(),
         start: 12,
         end: 14,
     };
@@ -760,9 +835,10 @@ fn test_find_poetry_revia_qaton() {
     // Revia directly followed by Oleh We Yored (1 word)
     let sc = SentenceContext::new("בּראשׁית בּרא אלהים א֗ת ה֫שּׁמי֥ם ואת הארץ׃", Context::Poetic);
     let expected = Match {
-        haystack: "\u{597}",
-        start: 21,
-        end: 23,
+        haystack: &sc.sentence,
+        accent: PoetryAccent::ReviaQaton.into(),
+        start: 39,
+        end: 41,
     };
     assert_eq!(
         sc.find_accent(PoetryAccent::ReviaQaton.into()),
@@ -771,9 +847,10 @@ fn test_find_poetry_revia_qaton() {
     // Revia directly followed by Oleh We Yored (2 words)
     let sc = SentenceContext::new("בּראשׁית בּרא אלהים א֗ת ה֫שּׁמים וא֥ת הארץ׃", Context::Poetic);
     let expected = Match {
-        haystack: "\u{597}",
-        start: 21,
-        end: 23,
+        haystack: &sc.sentence,
+        accent: PoetryAccent::ReviaQaton.into(),
+        start: 39,
+        end: 41,
     };
     assert_eq!(
         sc.find_accent(PoetryAccent::ReviaQaton.into()),
@@ -796,7 +873,8 @@ fn test_find_poetry_revia_qaton() {
 fn test_find_poetry_dechi() {
     let sc = SentenceContext::new("את־אבר֭הם", Context::Poetic);
     let expected = Match {
-        haystack: "TODO: insert single character",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::Dechi.into(),
         start: 12,
         end: 14,
     };
@@ -808,7 +886,8 @@ fn test_find_poetry_dechi() {
 fn test_find_poetry_pazer() {
     let sc = SentenceContext::new("את־אבר֡הם", Context::Poetic);
     let expected = Match {
-        haystack: "TODO: insert single character",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::Pazer.into(),
         start: 12,
         end: 14,
     };
@@ -821,7 +900,8 @@ fn test_find_poetry_mehuppakh_legarmeh() {
     // MehuppakhLegarmeh, with Paseq
     let sc = SentenceContext::new(" את־אברהם֤ ׀ מזמ֗ור", Context::Poetic);
     let expected = Match {
-        haystack: "TODO::Outermatch",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::MehuppakhLegarmeh.into(),
         start: 17,
         end: 22,
     };
@@ -832,7 +912,8 @@ fn test_find_poetry_mehuppakh_legarmeh() {
     // MehuppakhLegarmeh, with Vertical Bar
     let sc = SentenceContext::new(" את־אברהם֤ | מזמ֗ור", Context::Poetic);
     let expected = Match {
-        haystack: "TODO::Outermatch",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::MehuppakhLegarmeh.into(),
         start: 17,
         end: 21,
     };
@@ -849,7 +930,8 @@ fn test_find_poetry_azla_legarmeh() {
     // AzlaLegarmeh, with Paseq + no space
     let sc = SentenceContext::new(" את־אברה֨ם׀ א־אם", Context::Poetic);
     let expected = Match {
-        haystack: "TODO::Outermatch",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::AzlaLegarmeh.into(),
         start: 15,
         end: 21,
     };
@@ -860,7 +942,8 @@ fn test_find_poetry_azla_legarmeh() {
     // AzlaLegarmeh, with Paseq + 1 space
     let sc = SentenceContext::new(" את־אברה֨ם ׀ א־אם", Context::Poetic);
     let expected = Match {
-        haystack: "TODO::Outermatch",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::AzlaLegarmeh.into(),
         start: 15,
         end: 22,
     };
@@ -871,7 +954,8 @@ fn test_find_poetry_azla_legarmeh() {
     // AzlaLegarmeh, with Vertical Bar + no space
     let sc = SentenceContext::new(" את־אברה֨ם| א־אם", Context::Poetic);
     let expected = Match {
-        haystack: "TODO::Outermatch",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::AzlaLegarmeh.into(),
         start: 15,
         end: 20,
     };
@@ -882,7 +966,8 @@ fn test_find_poetry_azla_legarmeh() {
     // AzlaLegarmeh, with Vertical Bar + 1 space
     let sc = SentenceContext::new(" את־אברה֨ם | א־אם", Context::Poetic);
     let expected = Match {
-        haystack: "TODO::Outermatch",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::AzlaLegarmeh.into(),
         start: 15,
         end: 21,
     };
@@ -896,12 +981,13 @@ fn test_find_poetry_azla_legarmeh() {
 }
 #[test]
 fn test_find_poetry_munnach() {
+    let sc = SentenceContext::new("את־אבר֣הם", Context::Poetic);
     let expected = Match {
-        haystack: "TODO: insert single character",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::Munach.into(),
         start: 12,
         end: 14,
     };
-    let sc = SentenceContext::new("את־אבר֣הם", Context::Poetic);
     assert_eq!(sc.find_accent(PoetryAccent::Munach.into()), Some(expected));
     let sc = SentenceContext::new("את־אברהם", Context::Poetic);
     assert_eq!(sc.find_accent(PoetryAccent::Munach.into()), None);
@@ -914,9 +1000,10 @@ fn test_find_poetry_merkha() {
     // One Merkha
     let sc = SentenceContext::new("בּראשׁית בּרא אלהים א֥ת השּׁמים ואת הארץ׃", Context::Poetic);
     let expected = Match {
-        haystack: "\u{5a5}",
-        start: 21,
-        end: 23,
+        haystack: &sc.sentence,
+        accent: PoetryAccent::Merkha.into(),
+        start: 39,
+        end: 41,
     };
     assert_eq!(sc.find_accent(PoetryAccent::Merkha.into()), Some(expected));
     // Tsinnorit + Merkha (1w)
@@ -928,9 +1015,10 @@ fn test_find_poetry_merkha() {
     // Tsinnorit + Merkha (3w)
     let sc = SentenceContext::new("בּראשׁית בּר֘א אלהים א֥ת השּׁמים ואת הארץ׃", Context::Poetic);
     let expected = Match {
-        haystack: "\u{5a5}",
-        start: 22,
-        end: 24,
+        haystack: &sc.sentence,
+        accent: PoetryAccent::Merkha.into(),
+        start: 41,
+        end: 43,
     };
     assert_eq!(sc.find_accent(PoetryAccent::Merkha.into()), Some(expected));
     // Oleh + Merkha (1w)
@@ -942,9 +1030,10 @@ fn test_find_poetry_merkha() {
     // Oleh + Merkha (3w)
     let sc = SentenceContext::new("בּראשׁית בּר֫א אלהים א֥ת השּׁ֥מים ואת הארץ׃", Context::Poetic);
     let expected = Match {
-        haystack: "\u{5a5}",
-        start: 22,
-        end: 24,
+        haystack: &sc.sentence,
+        accent: PoetryAccent::Merkha.into(),
+        start: 41,
+        end: 43,
     };
     assert_eq!(sc.find_accent(PoetryAccent::Merkha.into()), Some(expected));
 }
@@ -953,7 +1042,8 @@ fn test_find_poetry_merkha() {
 fn test_find_poetry_illuy() {
     let sc = SentenceContext::new("את־אב֬רהם", Context::Poetic);
     let expected = Match {
-        haystack: "TODO: insert single character",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::Illuy.into(),
         start: 10,
         end: 12,
     };
@@ -965,7 +1055,8 @@ fn test_find_poetry_illuy() {
 fn test_find_poetry_tarcha() {
     let sc = SentenceContext::new("את־אבר֖הם", Context::Poetic);
     let expected = Match {
-        haystack: "TODO: insert single character",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::Tarcha.into(),
         start: 12,
         end: 14,
     };
@@ -977,7 +1068,8 @@ fn test_find_poetry_tarcha() {
 fn test_find_poetry_galgal() {
     let sc = SentenceContext::new("את־אבר֪הם", Context::Poetic);
     let expected = Match {
-        haystack: "TODO: insert single character",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::Galgal.into(),
         start: 12,
         end: 14,
     };
@@ -993,9 +1085,10 @@ fn test_find_poetry_mehuppakh() {
     // One Mehuppach
     let sc = SentenceContext::new("בּראשׁית בּרא אלהים א֤ת השּׁמים ואת הארץ׃", Context::Poetic);
     let expected = Match {
-        haystack: "\u{5a4}",
-        start: 21,
-        end: 23,
+        haystack: &sc.sentence,
+        accent: PoetryAccent::Mehuppakh.into(),
+        start: 39,
+        end: 41,
     };
     assert_eq!(
         sc.find_accent(PoetryAccent::Mehuppakh.into()),
@@ -1010,9 +1103,10 @@ fn test_find_poetry_mehuppakh() {
     // One Mehuppach, part of Tsinnorit Mappach (three words)
     let sc = SentenceContext::new("בּראשׁית בּ֘רא אלהים א֤ת השּׁמים ואת הארץ׃", Context::Poetic);
     let expected = Match {
-        haystack: "\u{5a4}",
-        start: 22,
-        end: 24,
+        haystack: &sc.sentence,
+        accent: PoetryAccent::Mehuppakh.into(),
+        start: 41,
+        end: 43,
     };
     assert_eq!(
         sc.find_accent(PoetryAccent::Mehuppakh.into()),
@@ -1033,9 +1127,10 @@ fn test_find_poetry_mehuppakh() {
     // One Mehuppach, part of 'Mehuppach Legarmeh' (too many spaces)
     let sc = SentenceContext::new("בּראשׁית בּרא אלהים את השּׁמים וא֤ת    ׀ הארץ׃", Context::Poetic);
     let expected = Match {
-        haystack: "\u{5a4}",
-        start: 33,
-        end: 35,
+        haystack: &sc.sentence,
+        accent: PoetryAccent::Mehuppakh.into(),
+        start: 61,
+        end: 63,
     };
     assert_eq!(
         sc.find_accent(PoetryAccent::Mehuppakh.into()),
@@ -1044,9 +1139,10 @@ fn test_find_poetry_mehuppakh() {
     // //One Mehuppach, part of Mehuppach Legarmeh (no space), followed with a Mehuppach
     let sc = SentenceContext::new("בּרא֤שׁית בּרא אלהים את השּׁמים וא֤ת׀ האר֤ץ׃", Context::Poetic);
     let expected = Match {
-        haystack: "\u{5a4}",
-        start: 4,
-        end: 6,
+        haystack: &sc.sentence,
+        accent: PoetryAccent::Mehuppakh.into(),
+        start: 8,
+        end: 10,
     };
     assert_eq!(
         sc.find_accent(PoetryAccent::Mehuppakh.into()),
@@ -1055,9 +1151,10 @@ fn test_find_poetry_mehuppakh() {
     // One Mehuppach, part of Mehuppach Legarmeh (one space), followed with a Mehuppach
     let sc = SentenceContext::new("בּרא֤שׁית בּרא אלהים את השּׁמים וא֤ת ׀ האר֤ץ׃", Context::Poetic);
     let expected = Match {
-        haystack: "\u{5a4}",
-        start: 4,
-        end: 6,
+        haystack: &sc.sentence,
+        accent: PoetryAccent::Mehuppakh.into(),
+        start: 8,
+        end: 10,
     };
     assert_eq!(
         sc.find_accent(PoetryAccent::Mehuppakh.into()),
@@ -1070,7 +1167,8 @@ fn test_find_poetry_azla() {
     // contains Azla
     let sc = SentenceContext::new(" את־אברה֨ם א־אם", Context::Poetic);
     let expected = Match {
-        haystack: "TODO::Outermatch",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::Azla.into(),
         start: 15,
         end: 17,
     };
@@ -1078,7 +1176,8 @@ fn test_find_poetry_azla() {
     // contains Azla and Azla Legarmeh
     let sc = SentenceContext::new(" אה֨ת־אברה֨ם ׀ א־אם", Context::Poetic);
     let expected = Match {
-        haystack: "TODO::Outermatch",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::Azla.into(),
         start: 5,
         end: 11,
     };
@@ -1101,7 +1200,8 @@ fn test_find_poetry_shalshelet_qetannah() {
     // Shalshelet
     let sc = SentenceContext::new("יצחק אל־יעק֓ב ויברך", Context::Poetic);
     let expected = Match {
-        haystack: "TODO::Outermatch",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::ShalsheletQetannah.into(),
         start: 21,
         end: 23,
     };
@@ -1127,7 +1227,8 @@ fn test_find_poetry_tsinnorit_merkha() {
     // accent in a single word
     let sc = SentenceContext::new("אא֘תאב֥רהם", Context::Poetic);
     let expected = Match {
-        haystack: "TODO::2CodePoints",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::TsinnoritMerkha.into(),
         start: 4,
         end: 14,
     };
@@ -1144,7 +1245,8 @@ fn test_find_poetry_tsinnorit_merkha() {
     // accent in two words seperated by Maqqeph
     let sc = SentenceContext::new("את־א֘ב֥רהם", Context::Poetic);
     let expected = Match {
-        haystack: "TODO::2CodePoints",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::TsinnoritMerkha.into(),
         start: 8,
         end: 14,
     };
@@ -1161,7 +1263,8 @@ fn test_find_poetry_tsinnorit_merkha() {
     // accent in two words
     let sc = SentenceContext::new("את־א֘בם ב֥רהם", Context::Poetic);
     let expected = Match {
-        haystack: "TODO::2CodePoints",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::TsinnoritMerkha.into(),
         start: 8,
         end: 19,
     };
@@ -1180,11 +1283,28 @@ fn test_find_poetry_tsinnorit_merkha() {
     assert_eq!(sc.find_accent(PoetryAccent::TsinnoritMerkha.into()), None);
 }
 #[test]
+fn test_find_poetry_tsinnorit_merkha_nearest_merkha() {
+    // the nearest Merkha completes the TsinnoritMerkha
+    // (the baseline regex was greedy and ended at the Merkha of the next word: 2..19)
+    let sc = SentenceContext::new("א֘להי֥ם א֥ת", Context::Poetic);
+    let expected = Match {
+        haystack: &sc.sentence,
+        accent: PoetryAccent::TsinnoritMerkha.into(),
+        start: 2,
+        end: 12,
+    };
+    assert_eq!(
+        sc.find_accent(PoetryAccent::TsinnoritMerkha.into()),
+        Some(expected)
+    );
+}
+#[test]
 fn test_find_poetry_tsinnorit_mahpakh() {
     // accent in a single word
     let sc = SentenceContext::new("את־א֘ב֤רהם אהם", Context::Poetic);
     let expected = Match {
-        haystack: "TODO::2CodePoints",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::TsinnoritMahpakh.into(),
         start: 8,
         end: 14,
     };
@@ -1201,7 +1321,8 @@ fn test_find_poetry_tsinnorit_mahpakh() {
     // accent in two words seperated by Maqqeph, without Mahpakh
     let sc = SentenceContext::new("אא֘ת־אב֤רהם אהם", Context::Poetic);
     let expected = Match {
-        haystack: "TODO::2CodePoints",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::TsinnoritMahpakh.into(),
         start: 4,
         end: 16,
     };
@@ -1218,7 +1339,8 @@ fn test_find_poetry_tsinnorit_mahpakh() {
     // accent in two words
     let sc = SentenceContext::new("את־א֘ברהם אהאב֤ם", Context::Poetic);
     let expected = Match {
-        haystack: "TODO::2CodePoints",
+        haystack: &sc.sentence,
+        accent: PoetryAccent::TsinnoritMahpakh.into(),
         start: 8,
         end: 29,
     };
@@ -1236,3 +1358,78 @@ fn test_find_poetry_tsinnorit_mahpakh() {
     let sc = SentenceContext::new("את־א֘ב רהם אהאב֤ם", Context::Poetic);
     assert_eq!(sc.find_accent(PoetryAccent::TsinnoritMahpakh.into()), None);
 }
+#[test]
+fn test_find_poetry_tsinnorit_mahpakh_nearest_mahpakh() {
+    // the nearest Mahpakh completes the TsinnoritMahpakh
+    // (the baseline regex was greedy and ended at the Mahpakh of the next word: 2..19)
+    let sc = SentenceContext::new("א֘להי֤ם א֤ת", Context::Poetic);
+    let expected = Match {
+        haystack: &sc.sentence,
+        accent: PoetryAccent::TsinnoritMahpakh.into(),
+        start: 2,
+        end: 12,
+    };
+    assert_eq!(
+        sc.find_accent(PoetryAccent::TsinnoritMahpakh.into()),
+        Some(expected)
+    );
+}
+#[test]
+fn test_find_match_details() {
+    // Single code-point accent
+    let sc = SentenceContext::new("בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים אֵ֥ת", Context::Prosaic);
+    let found = sc.find_accent(ProseAccent::Atnach.into()).unwrap();
+    assert_eq!(found.as_str(), "\u{0591}");
+    assert_eq!(found.accent(), ProseAccent::Atnach.into());
+    assert_eq!(found.word(), "אֱלֹהִ֑ים");
+    // Two code-point accent
+    let sc = SentenceContext::new("בְּהִ֑ים֓ ׀ אֵ֥ץ׃", Context::Prosaic);
+    let found = sc.find_accent(ProseAccent::Shalshelet.into()).unwrap();
+    assert_eq!(found.as_str(), "\u{0593} \u{05C0}");
+    assert_eq!(found.accent(), ProseAccent::Shalshelet.into());
+    assert_eq!(found.word(), "בְּהִ֑ים֓ ׀");
+    // Word connected by a Maqqeph
+    let sc = SentenceContext::new("וַֽיְהִי־כֵֽן׃", Context::Prosaic);
+    let found = sc.find_accent(PseudoAccent::Maqqeph.into()).unwrap();
+    assert_eq!(found.as_str(), "\u{05BE}");
+    assert_eq!(found.word(), "וַֽיְהִי־כֵֽן׃");
+}
+#[test]
+fn test_find_poetry_byte_offsets() {
+    // Merkha
+    let sc = SentenceContext::new("בּראשׁית בּר֘א אלהים א֥ת השּׁמים׃", Context::Poetic);
+    let found = sc.find_accent(PoetryAccent::Merkha.into()).unwrap();
+    assert_eq!(&sc.sentence[found.range()], "\u{05A5}");
+    // Mehuppakh
+    let sc = SentenceContext::new("בּראשׁית בּרא אלהים א֤ת השּׁמים׃", Context::Poetic);
+    let found = sc.find_accent(PoetryAccent::Mehuppakh.into()).unwrap();
+    assert_eq!(&sc.sentence[found.range()], "\u{05A4}");
+    // Revia Gadol
+    let sc = SentenceContext::new("בּראשׁית בּרא אלהים א֗ת השּׁמים׃", Context::Poetic);
+    let found = sc.find_accent(PoetryAccent::ReviaGadol.into()).unwrap();
+    assert_eq!(&sc.sentence[found.range()], "\u{0597}");
+    // Revia Qaton
+    let sc = SentenceContext::new("בּראשׁית בּרא אלהים א֗ת ה֫שּׁמי֥ם׃", Context::Poetic);
+    let found = sc.find_accent(PoetryAccent::ReviaQaton.into()).unwrap();
+    assert_eq!(&sc.sentence[found.range()], "\u{0597}");
+    // every match of find_iter() slices exactly the accent
+    let sc = SentenceContext::new("בּ֥ראשׁית בּר֥א אלהים א֥ת השּׁמים׃", Context::Poetic);
+    assert_eq!(sc.find_iter(PoetryAccent::Merkha.into()).count(), 3);
+    assert!(sc
+        .find_iter(PoetryAccent::Merkha.into())
+        .all(|found| &sc.sentence[found.range()] == "\u{05A5}"));
+}
+#[test]
+fn test_find_char_and_grapheme_offsets() {
+    let sc = SentenceContext::new("בּראשׁית בּרא אלהים א֥ת השּׁמים׃", Context::Poetic);
+    let found = sc.find_accent(PoetryAccent::Merkha.into()).unwrap();
+    assert_eq!(found.range(), 39..41);
+    assert_eq!(found.char_range(), 21..22);
+    // בּ ר א שׁ י ת ' ' בּ ר א ' ' א ל ה י ם ' ' א֥
+    assert_eq!(found.grapheme_range(), 17..18);
+    // Two code-point accent stretching over two graphemes
+    let sc = SentenceContext::new("בְּהִ֑ים֓ ׀ אֵ֥ץ׃", Context::Prosaic);
+    let found = sc.find_accent(ProseAccent::Shalshelet.into()).unwrap();
+    assert_eq!(found.char_range(), 8..11);
+    assert_eq!(found.grapheme_range(), 3..6);
+}
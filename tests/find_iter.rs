@@ -0,0 +1,116 @@
+use hebrew_accents::{
+    Context, Error, Match, PoetryAccent, ProseAccent, PseudoAccent, SentenceContext,
+};
+
+#[test]
+fn test_find_iter_prose_tiphcha() {
+    // Five Tiphcha's in one sentence
+    let sc = SentenceContext::new(
+        "ויּ֣ר֖א עשׂ֔ו כּ֥י רע֖ות נ֣ות כּ֖נ֑ען בּעינ֖י יצח֥ק א֖בֽיו׃",
+        Context::Prosaic,
+    );
+    let starts: Vec<usize> = sc
+        .find_iter(ProseAccent::Tiphcha.into())
+        .map(|m| m.start())
+        .collect();
+    assert_eq!(starts, vec![10, 39, 59, 80, 98]);
+    // Every match is exactly one code-point (two bytes)
+    assert!(sc
+        .find_iter(ProseAccent::Tiphcha.into())
+        .all(|m| m.len() == 2));
+    // The first match equals the result of find_accent()
+    assert_eq!(
+        sc.find_iter(ProseAccent::Tiphcha.into()).next(),
+        sc.find_accent(ProseAccent::Tiphcha.into())
+    );
+}
+#[test]
+fn test_find_iter_prose_no_match() {
+    // Accent not present
+    let sc = SentenceContext::new("בּראשׁית בּרא אלהים את השּׁמים ואת הארץ׃", Context::Prosaic);
+    assert_eq!(sc.find_iter(ProseAccent::Tiphcha.into()).count(), 0);
+    // Prose accent in a poetic context
+    let sc = SentenceContext::new("ויּ֣ר֖א עשׂ֔ו כּ֥י רע֖ות׃", Context::Poetic);
+    assert_eq!(sc.find_iter(ProseAccent::ZaqephQaton.into()).count(), 0);
+}
+#[test]
+fn test_find_iter_prose_munach() {
+    // Two Munach's and one Legarmeh
+    let sc = SentenceContext::new("בּראשׁית בּ֣רא א֣להים׀ את השּׁמ֣ים ואת הארץ׃", Context::Prosaic);
+    let found: Vec<Match> = sc.find_iter(ProseAccent::Munach.into()).collect();
+    assert_eq!(found.len(), 2);
+    assert_eq!(sc.find_iter(ProseAccent::Legarmeh.into()).count(), 1);
+}
+#[test]
+fn test_find_iter_prose_shalshelet() {
+    // Two Shalshelet's, both with a Paseq
+    let sc = SentenceContext::new("בְּהִ֑ים֓׀ אֵ֥ץ בְּהִים֓ ׀ אֵ֥ץ׃", Context::Prosaic);
+    let found: Vec<Match> = sc.find_iter(ProseAccent::Shalshelet.into()).collect();
+    assert_eq!(found.len(), 2);
+    assert_eq!((found[0].start(), found[0].end()), (16, 20));
+    assert_eq!(
+        sc.find_iter(ProseAccent::Shalshelet.into()).next(),
+        sc.find_accent(ProseAccent::Shalshelet.into())
+    );
+}
+#[test]
+fn test_find_iter_poetry_merkha() {
+    // Two Merkha's, one Merkha as part of Tsinnorit Merkha
+    let sc = SentenceContext::new("בּראשׁית בּר֥א א֘להי֥ם א֥ת השּׁמים ואת הארץ׃", Context::Poetic);
+    assert_eq!(sc.find_iter(PoetryAccent::Merkha.into()).count(), 2);
+    assert_eq!(
+        sc.find_iter(PoetryAccent::TsinnoritMerkha.into()).count(),
+        1
+    );
+    assert_eq!(
+        sc.find_iter(PoetryAccent::Merkha.into()).next(),
+        sc.find_accent(PoetryAccent::Merkha.into())
+    );
+}
+#[test]
+fn test_find_iter_poetry_revia() {
+    // One Revia Gadol and one Revia Qaton (followed by Oleh We Yored)
+    let sc = SentenceContext::new("בּראשׁית בּר֗א אלהים את השּׁמ֗ים ו֫את הא֥רץ׃", Context::Poetic);
+    assert_eq!(sc.find_iter(PoetryAccent::ReviaGadol.into()).count(), 1);
+    assert_eq!(sc.find_iter(PoetryAccent::ReviaQaton.into()).count(), 1);
+}
+#[test]
+fn test_find_iter_poetry_revia_mugrash() {
+    // Ps 23:1, the Revia Mugrash is written with a Geresh Muqdam
+    let sc = SentenceContext::new("מִזְמ֥וֹר לְדָוִ֑ד יְהֹוָ֥ה רֹ֝עִ֗י לֹ֣א אֶחְסָֽר׃", Context::Poetic);
+    assert_eq!(sc.find_iter(PoetryAccent::ReviaGadol.into()).count(), 0);
+    assert_eq!(sc.find_iter(PoetryAccent::ReviaMugrash.into()).count(), 1);
+    assert_eq!(sc.find_accent(PoetryAccent::ReviaGadol.into()), None);
+}
+#[test]
+fn test_try_find_iter() {
+    let sc = SentenceContext::new(
+        "ויּ֣ר֖א עשׂ֔ו כּ֥י רע֖ות נ֣ות כּ֖נ֑ען בּעינ֖י יצח֥ק א֖בֽיו׃",
+        Context::Prosaic,
+    );
+    assert_eq!(
+        sc.try_find_iter(ProseAccent::Mayela.into())
+            .unwrap()
+            .map(|m| m.start())
+            .collect::<Vec<usize>>(),
+        vec![59, 98]
+    );
+    assert!(matches!(
+        sc.try_find_iter(PoetryAccent::Tarcha.into()),
+        Err(Error::ContextMismatch { .. })
+    ));
+}
+#[test]
+fn test_find_iter_pseudo_paseq() {
+    // every Paseq mark, also the one of the Legarmeh
+    let sc = SentenceContext::new("בּראשׁית בּ֣רא א֣להים ׀ את ׀ הארץ׃", Context::Prosaic);
+    assert_eq!(sc.find_iter(PseudoAccent::Paseq.into()).count(), 2);
+    assert_eq!(sc.find_iter(ProseAccent::Legarmeh.into()).count(), 1);
+    // the analysis reports the Paseq of the Legarmeh as part of the Legarmeh
+    let paseqs = sc
+        .analyze()
+        .into_iter()
+        .filter(|(accent, _)| *accent == PseudoAccent::Paseq.into())
+        .count();
+    assert_eq!(paseqs, 1);
+}
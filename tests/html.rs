@@ -0,0 +1,53 @@
+use hebrew_accents::{Context, HtmlSpans, SentenceContext, DEFAULT_STYLESHEET};
+
+#[test]
+fn test_to_html_words() {
+    let sc = SentenceContext::new("וַֽיְהִי־בֹ֖קֶר י֥וֹם", Context::Prosaic);
+    let html = sc.to_html(HtmlSpans::Word);
+    // a Meteg is the principal accent of a word without another accent,
+    // the Maqqeph between the words is not wrapped
+    assert!(html.contains("data-accent=\"Meteg\""));
+    assert!(html.contains("\">וַֽיְהִי</span>־<span "));
+    assert!(
+        html.contains("data-accent=\"Tiphcha\" data-category=\"disjunctive\" data-strength=\"8\"")
+    );
+    assert!(html.contains(">בֹ֖קֶר</span> <span class=\"accent conjunctive\" data-accent=\"Merkha\""));
+    assert!(html.ends_with(">י֥וֹם</span></p>"));
+}
+#[test]
+fn test_to_html_marks() {
+    let sc = SentenceContext::new("בּ֣רא א֣להים׀ את׃", Context::Prosaic);
+    let html = sc.to_html(HtmlSpans::Mark);
+    // both code-points of Legarmeh are wrapped
+    assert_eq!(html.matches("data-accent=\"Legarmeh\"").count(), 2);
+    assert_eq!(html.matches("data-accent=\"Munach\"").count(), 1);
+    assert!(html.contains("<span class=\"accent pseudo\" data-accent=\"Soph Pasuq\""));
+    // the consonant carrying the accent is wrapped with its points
+    assert!(html.contains("\">בּ֣</span>רא <span "));
+    assert!(html.contains("\">א֣</span>להים<span "));
+    // the tooltip holds the meaning and the comment
+    assert!(html.contains(
+        "title=\"Legarmeh: for or by itself, independant (Munach with Passeq; Before Revia)\""
+    ));
+}
+#[test]
+fn test_to_html_marks_nested() {
+    // Meteg and Tiphcha on the same consonant
+    let sc = SentenceContext::new("בָּֽ֖רָא אֱלֹהִ֑ים", Context::Prosaic);
+    let html = sc.to_html(HtmlSpans::Mark);
+    assert!(html.contains("\"><span class=\"accent disjunctive\" data-accent=\"Tiphcha\""));
+    assert!(html.contains("\">בָּֽ֖</span></span>רָא"));
+}
+#[test]
+fn test_to_html_escaping() {
+    let sc = SentenceContext::new("<b>\"&'</b> בָּרָ֣א", Context::Prosaic);
+    let html = sc.to_html(HtmlSpans::Word);
+    assert!(html.contains("&lt;b&gt;&quot;&amp;&#39;&lt;/b&gt;"));
+    assert!(!html.contains("<b>"));
+}
+#[test]
+fn test_default_stylesheet() {
+    assert!(DEFAULT_STYLESHEET.contains(".hebrew-accents .accent.disjunctive"));
+    assert!(DEFAULT_STYLESHEET.contains(".hebrew-accents .accent.conjunctive"));
+    assert!(DEFAULT_STYLESHEET.contains("direction: rtl"));
+}
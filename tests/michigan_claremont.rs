@@ -0,0 +1,123 @@
+use hebrew_accents::{
+    accents_for_mc_code, decode_michigan_claremont, encode_michigan_claremont, normalize, Context,
+    Error, HebrewAccent, PoetryAccent, ProseAccent, SentenceContext,
+};
+
+const GEN_1_1: &str = "בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים אֵ֥ת הַשָּׁמַ֖יִם וְאֵ֥ת הָאָֽרֶץ׃";
+const GEN_1_1_MC: &str =
+    "B.:R\")$I73YT B.FRF74) ):ELOHI92YM )\"71T HA$.FMA73YIM W:)\"71T HF)F75REC00";
+
+#[test]
+fn test_decode_verse() {
+    assert_eq!(
+        decode_michigan_claremont(GEN_1_1_MC).unwrap(),
+        normalize(GEN_1_1)
+    );
+}
+#[test]
+fn test_encode_verse() {
+    assert_eq!(encode_michigan_claremont(GEN_1_1).unwrap(), GEN_1_1_MC);
+}
+#[test]
+fn test_round_trip() {
+    let mc = "WA73Y.O71)MER ):ELOHI80YM Y:HI74Y )O92WR WA75Y:HIY-)O75WR00";
+    let decoded = decode_michigan_claremont(mc).unwrap();
+    assert_eq!(encode_michigan_claremont(&decoded).unwrap(), mc);
+}
+#[test]
+fn test_final_forms() {
+    assert_eq!(decode_michigan_claremont("MELEK:").unwrap(), "מֶלֶךְ");
+    assert_eq!(
+        decode_michigan_claremont("KOL-HF)F75REC").unwrap(),
+        "כֹל־הָאָֽרֶץ"
+    );
+    assert_eq!(encode_michigan_claremont("מֶלֶךְ").unwrap(), "MELEK:");
+    // section markers
+    assert_eq!(
+        decode_michigan_claremont("HF)F75REC00 P").unwrap(),
+        "הָאָֽרֶץ׃ פ"
+    );
+    assert_eq!(decode_michigan_claremont("S").unwrap(), "ס");
+}
+#[test]
+fn test_shin_and_sin() {
+    assert_eq!(decode_michigan_claremont("$").unwrap(), "\u{05E9}\u{05C1}");
+    assert_eq!(decode_michigan_claremont("&").unwrap(), "\u{05E9}\u{05C2}");
+    assert_eq!(decode_michigan_claremont("#").unwrap(), "\u{05E9}");
+    // precomposed Shin with Dagesh and Shin dot
+    assert_eq!(
+        encode_michigan_claremont("\u{FB2C}\u{05B8}").unwrap(),
+        "$.F"
+    );
+}
+#[test]
+fn test_paseq() {
+    let decoded = decode_michigan_claremont("):ELOHI63YM05 ):ELOHI63YM|").unwrap();
+    assert_eq!(decoded, "אֱלֹהִ֨ים ׀ אֱלֹהִ֨ים ׀");
+    assert_eq!(
+        encode_michigan_claremont(&decoded).unwrap(),
+        "):ELOHI63YM 05 ):ELOHI63YM 05"
+    );
+}
+#[test]
+fn test_positional_codes() {
+    // Pashta on the stressed syllable and on the last consonant
+    let decoded = decode_michigan_claremont("HA33MAYIM03").unwrap();
+    assert_eq!(decoded, "הַ֙מַיִם֙");
+    assert_eq!(encode_michigan_claremont(&decoded).unwrap(), "HA33MAYIM03");
+    // Telisha Gedola on the first consonant, Meteg codes 35 and 95
+    assert_eq!(decode_michigan_claremont("H14").unwrap(), "ה֠");
+    assert_eq!(
+        encode_michigan_claremont(&decode_michigan_claremont("HF35)F95REC").unwrap()).unwrap(),
+        "HF75)F75REC"
+    );
+}
+#[test]
+fn test_morpheme_separators() {
+    assert_eq!(
+        decode_michigan_claremont("L:/DFWI92D").unwrap(),
+        decode_michigan_claremont("L:DFWI92D").unwrap()
+    );
+}
+#[test]
+fn test_invalid_transliteration() {
+    assert_eq!(
+        decode_michigan_claremont("B.FRF99)"),
+        Err(Error::InvalidTransliteration {
+            position: 5,
+            message: "unknown accent code 99".to_string()
+        })
+    );
+    assert!(matches!(
+        decode_michigan_claremont("B.FRF7"),
+        Err(Error::InvalidTransliteration { position: 5, .. })
+    ));
+    assert!(matches!(
+        decode_michigan_claremont("BFRF?"),
+        Err(Error::InvalidTransliteration { position: 4, .. })
+    ));
+    assert!(matches!(
+        encode_michigan_claremont("בָּרָא x"),
+        Err(Error::InvalidTransliteration { position: 13, .. })
+    ));
+}
+#[test]
+fn test_accents_for_mc_code() {
+    assert_eq!(
+        accents_for_mc_code("92", Context::Prosaic),
+        [HebrewAccent::from(ProseAccent::Atnach)]
+    );
+    let meteg = accents_for_mc_code("75", Context::Prosaic);
+    assert!(meteg.contains(&ProseAccent::Silluq.into()));
+    assert!(meteg.contains(&ProseAccent::Meteg.into()));
+    assert!(accents_for_mc_code("81", Context::Poetic).contains(&PoetryAccent::ReviaGadol.into()));
+    assert!(accents_for_mc_code("", Context::Prosaic).is_empty());
+}
+#[test]
+fn test_sentence_context() {
+    let sc = SentenceContext::from_michigan_claremont(GEN_1_1_MC, Context::Prosaic).unwrap();
+    assert!(sc.contains_accent(ProseAccent::Atnach.into()));
+    assert!(sc.contains_accent(ProseAccent::Silluq.into()));
+    assert_eq!(sc.to_michigan_claremont().unwrap(), GEN_1_1_MC);
+    assert!(SentenceContext::from_michigan_claremont("B?", Context::Prosaic).is_err());
+}
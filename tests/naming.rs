@@ -0,0 +1,52 @@
+use hebrew_accents::{
+    Accent, Context, HebrewAccent, Naming, PoetryAccent, ProseAccent, SentenceContext,
+    TraditionKind,
+};
+
+#[test]
+fn test_name_in() {
+    let atnach = ProseAccent::Atnach;
+    assert_eq!(atnach.name_in(TraditionKind::Ashkenazi), Some("Etnachta"));
+    assert_eq!(atnach.name_in(TraditionKind::Sephardi), Some("Atnach"));
+    assert_eq!(atnach.name_in(TraditionKind::Yemenite), Some("Etnacha"));
+    assert_eq!(
+        atnach.hebrew_name_in(TraditionKind::Ashkenazi),
+        Some("אֶתְנַחְתָּ֑א")
+    );
+    // the Segol code point has no Yemenite name
+    assert_eq!(ProseAccent::Segolta.name_in(TraditionKind::Yemenite), None);
+}
+#[test]
+fn test_name_in_combined_code_points() {
+    // Legarmeh is Munach followed by Paseq, the traditions name the code points only
+    assert!(ProseAccent::Legarmeh.traditions().is_empty());
+    assert_eq!(
+        PoetryAccent::OlehWeYored.name_in(TraditionKind::Sephardi),
+        None
+    );
+}
+#[test]
+fn test_all_names() {
+    let names = HebrewAccent::Prose(ProseAccent::Atnach).all_names();
+    assert_eq!(names.len(), 5);
+    assert_eq!(names[0], (Naming::Bhs, "Atnach"));
+    assert_eq!(
+        names[1],
+        (Naming::Tradition(TraditionKind::Ashkenazi), "Etnachta")
+    );
+}
+#[test]
+fn test_display_name() {
+    let ashkenazi = Naming::Tradition(TraditionKind::Ashkenazi);
+    assert_eq!(ProseAccent::Atnach.display_name(ashkenazi), "Etnachta");
+    assert_eq!(ProseAccent::Atnach.display_hebrew_name(ashkenazi), "אֶתְנַחְתָּ֑א");
+    // without a name in the tradition the BHS name is used
+    assert_eq!(ProseAccent::Legarmeh.display_name(ashkenazi), "Legarmeh");
+    assert_eq!(ProseAccent::Atnach.display_name(Naming::Bhs), "Atnach");
+    let sc = SentenceContext::new("בְּרֵאשִׁ֖ית בָּרָ֤א אֱלֹהִ֑ים", Context::Prosaic);
+    let diagnostics = sc.validate_servi();
+    assert!(diagnostics[0]
+        .message_in(ashkenazi)
+        .contains("before Etnachta"));
+    assert!(diagnostics[0].to_string().contains("before Atnach"));
+}
@@ -0,0 +1,93 @@
+use hebrew_accents::{
+    is_normalized, normalize, Context, CorpusFormat, CorpusReader, HebrewAccent, Normalization,
+    SentenceContext,
+};
+
+const VERSES: [&str; 3] = [
+    "בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים אֵ֥ת הַשָּׁמַ֖יִם וְאֵ֥ת הָאָֽרֶץ׃",
+    "וַיֹּ֥אמֶר אֱלֹהִ֖ים יְהִ֣י א֑וֹר וַֽיְהִי־אֽוֹר׃",
+    "וַיַּ֧רְא אֱלֹהִ֛ים אֶת־הָא֖וֹר כִּי־ט֑וֹב וַיַּבְדֵּ֣ל אֱלֹהִ֔ים בֵּ֥ין הָא֖וֹר וּבֵ֥ין הַחֹֽשֶׁךְ׃",
+];
+
+/// Puts the accents and the Meteg of every consonant before its other marks
+/// (the order of some sources)
+fn accents_first(text: &str) -> String {
+    let mut result = String::new();
+    let mut marks: Vec<char> = Vec::new();
+    for c in text.chars().chain(std::iter::once(' ')) {
+        if ('\u{0591}'..='\u{05C7}').contains(&c) && !"\u{05BE}\u{05C0}\u{05C3}\u{05C6}".contains(c)
+        {
+            marks.push(c);
+            continue;
+        }
+        marks.sort_by_key(|m| !(('\u{0591}'..='\u{05AF}').contains(m) || *m == '\u{05BD}'));
+        result.extend(marks.drain(..));
+        result.push(c);
+    }
+    result.pop();
+    result
+}
+
+fn accents(sentence: &str) -> Vec<HebrewAccent> {
+    SentenceContext::new(sentence, Context::Prosaic)
+        .analyze()
+        .into_iter()
+        .map(|(accent, _)| accent)
+        .collect()
+}
+
+#[test]
+fn test_normalize_orders() {
+    // Shin, Sin dot, Dagesh, Qamats, Meteg, Tiphcha in NFC order
+    let nfc = "\u{05E9}\u{05B8}\u{05BC}\u{05BD}\u{05C2}\u{0596}";
+    let canonical = "\u{05E9}\u{05C2}\u{05BC}\u{05B8}\u{05BD}\u{0596}";
+    assert_eq!(normalize(nfc), canonical);
+    assert_eq!(
+        normalize("\u{05E9}\u{0596}\u{05BD}\u{05B8}\u{05C2}\u{05BC}"),
+        canonical
+    );
+    assert!(is_normalized(canonical));
+    assert!(!is_normalized(nfc));
+    // Rafe after Dagesh, before the vowel
+    assert_eq!(
+        normalize("\u{05DB}\u{05B0}\u{05BF}"),
+        "\u{05DB}\u{05BF}\u{05B0}"
+    );
+    // spacing marks and other characters are kept in place
+    assert_eq!(
+        normalize("a\u{05BE}b \u{05C3} \u{05C0}"),
+        "a\u{05BE}b \u{05C3} \u{05C0}"
+    );
+}
+#[test]
+fn test_normalize_combining_grapheme_joiner() {
+    // an intentional Meteg before the vowel (left Meteg) is kept
+    let text = "\u{05D1}\u{05BD}\u{034F}\u{05B8}";
+    assert_eq!(normalize(text), text);
+}
+#[test]
+fn test_normalize_sources() {
+    for verse in VERSES {
+        let canonical = normalize(verse);
+        assert_eq!(normalize(&accents_first(verse)), canonical);
+        assert_eq!(normalize(&canonical), canonical);
+        assert_eq!(accents(&canonical), accents(verse), "{}", verse);
+    }
+}
+#[test]
+fn test_normalize_sentence_context() {
+    let source = accents_first(VERSES[0]);
+    assert_eq!(
+        SentenceContext::new(&source, Context::Prosaic).sentence,
+        source
+    );
+    let sc = SentenceContext::new_normalized(&source, Context::Prosaic);
+    assert_eq!(sc.sentence, normalize(VERSES[0]));
+    // the corpus reader
+    let verses: Vec<_> = CorpusReader::new(source.as_bytes(), CorpusFormat::Plain)
+        .normalization(Normalization::Canonical)
+        .collect();
+    assert_eq!(verses[0].as_ref().unwrap().sc, sc);
+    let verses: Vec<_> = CorpusReader::new(source.as_bytes(), CorpusFormat::Plain).collect();
+    assert_eq!(verses[0].as_ref().unwrap().sc.sentence, source);
+}
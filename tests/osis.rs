@@ -0,0 +1,115 @@
+#![cfg(feature = "osis")]
+
+use hebrew_accents::{
+    Book, Context, Error, Normalization, OsisReader, ProseAccent, Reference, SentenceContext,
+};
+
+/// Genesis 1:1, 1:3 and Job 3:1-2 as published by the OSHB
+const OSHB: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<osis xmlns="http://www.bibletechnologies.net/2003/OSIS/namespace">
+  <osisText osisIDWork="WLC" osisRefWork="Bible" xml:lang="he">
+    <div type="book" osisID="Gen">
+      <chapter osisID="Gen.1">
+        <verse osisID="Gen.1.1">
+          <w lemma="b/7225" n="1.0" morph="HR/Ncfsa" id="01xeN">בְּ/רֵאשִׁ֖ית</w>
+          <w lemma="1254 a" morph="HVqp3ms" id="01Nvk">בָּרָ֣א</w>
+          <w lemma="430" morph="HNcmpa" id="01TyA">אֱלֹהִ֑ים</w>
+          <w lemma="853" morph="HTo" id="01vuQ">אֵ֥ת</w>
+          <w lemma="d/8064" morph="HTd/Ncmpa" id="01Dbb">הַ/שָּׁמַ֖יִם</w>
+          <w lemma="c/853" morph="HC/To" id="01kTn">וְ/אֵ֥ת</w>
+          <w lemma="d/776" n="0" morph="HTd/Ncbsa" id="01MN4">הָ/אָֽרֶץ</w><seg type="x-sof-pasuq">׃</seg>
+        </verse>
+        <verse osisID="Gen.1.3">
+          <w lemma="c/559" morph="HC/Vqw3ms" id="01WsS">וַ/יֹּ֥אמֶר</w>
+          <w lemma="430" morph="HNcmpa" id="01MgA">אֱלֹהִ֖ים</w>
+          <w lemma="1961" morph="HVqj3ms" id="01dtQ">יְהִ֣י</w>
+          <w lemma="216" morph="HNcbsa" id="01x4k">א֑וֹר</w>
+          <w lemma="c/1961" n="1.0" morph="HC/Vqw3ms" id="01VcW">וַֽ/יְהִי</w><seg type="x-maqqef">־</seg><w lemma="216" morph="HNcbsa" id="01ABX">אֽוֹר</w><seg type="x-sof-pasuq">׃</seg>
+          <note type="exegesis">a note with &lt;text&gt; <w>ignored</w><milestone type="x-empty"/></note>
+          <seg type="x-pe">פ</seg>
+        </verse>
+      </chapter>
+    </div>
+    <div type="book" osisID="Job">
+      <chapter osisID="Job.3">
+        <verse osisID="Job.3.1"><w>אַחֲרֵי</w><seg type="x-maqqef">־</seg><w>כֵ֗ן</w><seg type="x-sof-pasuq">׃</seg></verse>
+        <verse osisID="Job.3.2"><w>וַ/יַּ֥עַן</w><w>אִיּ֗וֹב</w><seg type="x-paseq">׀</seg><w>וַ/יֹּאמַֽר</w><seg type="x-sof-pasuq">׃</seg></verse>
+      </chapter>
+    </div>
+  </osisText>
+</osis>
+"#;
+
+fn read(xml: &str) -> Vec<(Reference, SentenceContext)> {
+    OsisReader::new(xml.as_bytes())
+        .collect::<Result<_, _>>()
+        .unwrap()
+}
+
+#[test]
+fn test_osis_verses() {
+    let verses = read(OSHB);
+    let references: Vec<String> = verses.iter().map(|(r, _)| r.to_string()).collect();
+    assert_eq!(references, ["Gen 1:1", "Gen 1:3", "Job 3:1", "Job 3:2"]);
+    assert_eq!(verses[0].1.sentence, "בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים אֵ֥ת הַשָּׁמַ֖יִם וְאֵ֥ת הָאָֽרֶץ׃");
+    assert_eq!(verses[1].1.sentence, "וַיֹּ֥אמֶר אֱלֹהִ֖ים יְהִ֣י א֑וֹר וַֽיְהִי־אֽוֹר׃ פ");
+    assert_eq!(verses[3].1.sentence, "וַיַּ֥עַן אִיּ֗וֹב ׀ וַיֹּאמַֽר׃");
+    assert!(verses[0].1.contains_accent(ProseAccent::Atnach.into()));
+}
+#[test]
+fn test_osis_context() {
+    let contexts: Vec<Context> = read(OSHB).iter().map(|(_, sc)| sc.ctx).collect();
+    assert_eq!(
+        contexts,
+        [
+            Context::Prosaic,
+            Context::Prosaic,
+            Context::Prosaic,
+            Context::Poetic
+        ]
+    );
+}
+#[test]
+fn test_osis_normalization() {
+    // the Atnach before the Qamats
+    let xml = r#"<osis><verse osisID="Gen.1.1"><w>אָ֑</w></verse></osis>"#;
+    assert_eq!(read(xml)[0].1.sentence, "\u{05D0}\u{0591}\u{05B8}");
+    let verses: Vec<_> = OsisReader::new(xml.as_bytes())
+        .normalization(Normalization::Canonical)
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(verses[0].1.sentence, "\u{05D0}\u{05B8}\u{0591}");
+}
+#[test]
+fn test_osis_milestones() {
+    let xml = r#"<osis><osisText><chapter sID="Ps.1" osisID="Ps.1"/>
+        <verse sID="Ps.1.1" osisID="Ps.1.1"/><w>אַ֥שְֽׁרֵי</w><w>הָאִ֗ישׁ</w><verse eID="Ps.1.1"/>
+        <verse sID="Ps.1.2" osisID="Ps.1.2"/><w>כִּ֤י</w>
+        <verse sID="Ps.1.3" osisID="Ps.1.3"/><w>וְֽהָיָ֗ה</w>
+        </osisText></osis>"#;
+    let verses = read(xml);
+    assert_eq!(verses.len(), 3);
+    assert_eq!(verses[0].0, Reference::new(Book::Psalms, 1, 1).unwrap());
+    assert_eq!(verses[0].1.sentence, "אַ֥שְֽׁרֵי הָאִ֗ישׁ");
+    assert_eq!(verses[1].1.sentence, "כִּ֤י");
+    assert_eq!(verses[2].1.sentence, "וְֽהָיָ֗ה");
+}
+#[test]
+fn test_osis_open() {
+    let path = std::env::temp_dir().join(format!("hebrew_accents_osis_{}.xml", std::process::id()));
+    std::fs::write(&path, OSHB).unwrap();
+    let count = OsisReader::open(&path).unwrap().count();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(count, 4);
+    assert!(matches!(OsisReader::open(&path), Err(Error::Corpus(_))));
+}
+#[test]
+fn test_osis_errors() {
+    let mut reader =
+        OsisReader::new(r#"<osis><verse osisID="Foo.1.1"><w>א</w></verse></osis>"#.as_bytes());
+    assert!(matches!(reader.next(), Some(Err(Error::Corpus(_)))));
+    assert!(reader.next().is_none());
+    let mut reader =
+        OsisReader::new(r#"<osis><verse osisID="Gen.1.1"><w>א</w></x></osis>"#.as_bytes());
+    assert!(matches!(reader.next(), Some(Err(Error::Corpus(_)))));
+}
@@ -0,0 +1,89 @@
+use hebrew_accents::{Context, PoetryAccent, ProseAccent, SentenceContext};
+
+#[test]
+fn test_parse_tree_gen_1_1() {
+    let sc = SentenceContext::new("בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים אֵ֥ת הַשָּׁמַ֖יִם וְאֵ֥ת הָאָֽרֶץ׃", Context::Prosaic);
+    let tree = sc.parse_tree().unwrap();
+    assert_eq!(tree.accent(), Some(ProseAccent::Silluq.into()));
+    assert_eq!(tree.lord().as_str(), "הָאָֽרֶץ");
+    assert_eq!(tree.servi().len(), 1);
+    assert_eq!(tree.children().len(), 2);
+    // Atnach divides the verse, Tiphcha divides both halves
+    let atnach = &tree.children()[0];
+    assert_eq!(atnach.accent(), Some(ProseAccent::Atnach.into()));
+    assert_eq!(atnach.as_str(), "בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים");
+    assert_eq!(
+        atnach.children()[0].accent(),
+        Some(ProseAccent::Tiphcha.into())
+    );
+    assert_eq!(atnach.servi()[0].as_str(), "בָּרָ֣א");
+    assert_eq!(
+        tree.children()[1].accent(),
+        Some(ProseAccent::Tiphcha.into())
+    );
+    assert_eq!(tree.depth(), 3);
+    assert_eq!(tree.iter().count(), 4);
+    assert_eq!(tree.range(), 0..sc.sentence.len() - "׃".len());
+}
+#[test]
+fn test_parse_tree_display() {
+    let sc = SentenceContext::new("בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים אֵ֥ת הַשָּׁמַ֖יִם וְאֵ֥ת הָאָֽרֶץ׃", Context::Prosaic);
+    let expected = "\
+Silluq: הָאָֽרֶץ
+  Atnach: אֱלֹהִ֑ים
+    Tiphcha: בְּרֵאשִׁ֖ית
+    servus Munach: בָּרָ֣א
+  Tiphcha: הַשָּׁמַ֖יִם
+    servus Merkha: אֵ֥ת
+  servus Merkha: וְאֵ֥ת
+";
+    assert_eq!(sc.parse_tree().unwrap().to_string(), expected);
+}
+#[test]
+fn test_parse_tree_levels() {
+    // Zaqeph (a king) divides the Atnach domain, without a Tiphcha
+    // the Revia (a duke) divides the rest of the Atnach domain
+    let sc = SentenceContext::new("וַיֹּ֣אמֶר אֱלֹהִ֔ים יְהִ֥י רָקִ֗יעַ בְּת֣וֹךְ הַמָּ֑יִם", Context::Prosaic);
+    let tree = sc.parse_tree().unwrap();
+    assert_eq!(tree.accent(), Some(ProseAccent::Atnach.into()));
+    let accents: Vec<_> = tree.children().iter().map(|c| c.accent()).collect();
+    assert_eq!(
+        accents,
+        vec![
+            Some(ProseAccent::ZaqephQaton.into()),
+            Some(ProseAccent::Revia.into())
+        ]
+    );
+    // Revia (a duke) is subordinate to the Tiphcha (a king)
+    let sc = SentenceContext::new("הָאָ֗רֶץ הָיְתָ֥ה תֹ֖הוּ וָבֹ֑הוּ", Context::Prosaic);
+    let tree = sc.parse_tree().unwrap();
+    let tiphcha = &tree.children()[0];
+    assert_eq!(tiphcha.accent(), Some(ProseAccent::Tiphcha.into()));
+    assert_eq!(
+        tiphcha.children()[0].accent(),
+        Some(ProseAccent::Revia.into())
+    );
+    assert_eq!(tiphcha.servi()[0].as_str(), "הָיְתָ֥ה");
+}
+#[test]
+fn test_parse_tree_poetry() {
+    // Oleh We Yored divides the verse before Atnach
+    let sc = SentenceContext::new(
+        "אַ֥שְֽׁרֵי הָאִ֗ישׁ אֲשֶׁ֤ר ׀ לֹ֥א הָלַךְ֮ בַּעֲצַ֪ת רְשָׁ֫עִ֥ים וּבְדֶ֣רֶךְ חַ֭טָּאִים לֹ֥א עָמָ֑ד וּבְמוֹשַׁ֥ב לֵ֝צִ֗ים לֹ֣א יָשָֽׁב׃",
+        Context::Poetic,
+    );
+    let tree = sc.parse_tree().unwrap();
+    assert_eq!(tree.accent(), Some(PoetryAccent::Silluq.into()));
+    let accents: Vec<_> = tree.children().iter().map(|c| c.accent()).collect();
+    assert_eq!(
+        accents,
+        vec![
+            Some(PoetryAccent::OlehWeYored.into()),
+            Some(PoetryAccent::Atnach.into()),
+            Some(PoetryAccent::ReviaMugrash.into()),
+        ]
+    );
+    assert!(SentenceContext::new("", Context::Poetic)
+        .parse_tree()
+        .is_none());
+}
@@ -0,0 +1,91 @@
+use hebrew_accents::{Book, Context, Error, PoetryAccent, ProseAccent, Reference, SentenceContext};
+
+fn context(reference: &str) -> Context {
+    reference.parse::<Reference>().unwrap().context()
+}
+
+#[test]
+fn test_reference_parse() {
+    let expected = Reference::new(Book::FirstSamuel, 3, 4).unwrap();
+    for text in [
+        "1 Sam 3:4",
+        "1Sam 3:4",
+        "I Samuel 3.4",
+        " 1 sam. 3,4 ",
+        "שמואל א 3:4",
+    ] {
+        assert_eq!(text.parse::<Reference>().unwrap(), expected, "{}", text);
+    }
+    assert_eq!(
+        "Job 3:2".parse::<Reference>().unwrap(),
+        Reference {
+            book: Book::Job,
+            chapter: 3,
+            verse: 2
+        }
+    );
+    assert_eq!(
+        "Song of Songs 2:1".parse::<Reference>().unwrap().book,
+        Book::SongOfSongs
+    );
+}
+#[test]
+fn test_reference_parse_invalid() {
+    for text in [
+        "", "Ps", "Ps 23", "Foo 1:1", "Jo 1:1", "Ps 0:1", "Ps 1:0", "Mal 4:1",
+    ] {
+        assert!(
+            matches!(text.parse::<Reference>(), Err(Error::InvalidReference(_))),
+            "{}",
+            text
+        );
+    }
+    assert!(Reference::new(Book::Joel, 5, 1).is_err());
+    // the verse is not range-checked
+    let reference: Reference = "Ps 23:999".parse().unwrap();
+    assert_eq!(reference.verse, 999);
+}
+#[test]
+fn test_reference_display() {
+    for book in Book::ALL {
+        let reference = Reference::new(book, 1, 1).unwrap();
+        assert_eq!(
+            reference.to_string().parse::<Reference>().unwrap(),
+            reference
+        );
+        assert_eq!(book.english_name().parse::<Book>().unwrap(), book);
+        assert_eq!(book.hebrew_name().parse::<Book>().unwrap(), book);
+    }
+}
+#[test]
+fn test_reference_context() {
+    for (reference, ctx) in [
+        ("Gen 1:1", Context::Prosaic),
+        ("Ps 1:1", Context::Poetic),
+        ("Ps 150:6", Context::Poetic),
+        ("Prov 31:31", Context::Poetic),
+        ("Job 1:1", Context::Prosaic),
+        ("Job 2:13", Context::Prosaic),
+        ("Job 3:1", Context::Prosaic),
+        ("Job 3:2", Context::Poetic),
+        ("Job 42:6", Context::Poetic),
+        ("Job 42:7", Context::Prosaic),
+        ("Job 42:17", Context::Prosaic),
+        ("Lam 1:1", Context::Prosaic),
+        ("Song 1:1", Context::Prosaic),
+    ] {
+        assert_eq!(context(reference), ctx, "{}", reference);
+    }
+}
+#[test]
+fn test_sentence_context_for_reference() {
+    let sentence = "יצחק אל־יע֓קב ׀ ויברך";
+    let poetic = SentenceContext::for_reference(sentence, "Ps 1:1".parse().unwrap());
+    assert!(poetic.contains_accent(PoetryAccent::ShalsheletGadol.into()));
+    let prosaic = SentenceContext::for_reference(sentence, "Job 1:1".parse().unwrap());
+    assert_eq!(prosaic.ctx, Context::Prosaic);
+    assert!(!prosaic.contains_accent(PoetryAccent::ShalsheletGadol.into()));
+    let sentence = "ויּ֣ר֖א עשׂ֔ו כּ֥י רע֖ות׃";
+    let sc = SentenceContext::for_reference(sentence, "Gen 28:8".parse().unwrap());
+    assert!(sc.contains_accent(ProseAccent::Tiphcha.into()));
+}
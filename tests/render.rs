@@ -0,0 +1,41 @@
+use hebrew_accents::{AccentFilter, AccentType, Context, ProseAccent, SentenceContext};
+
+#[test]
+fn test_render_filtered_skeleton() {
+    let sc = SentenceContext::new("בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים אֵ֥ת הַשָּׁמַ֖יִם וְאֵ֥ת הָאָֽרֶץ׃", Context::Prosaic);
+    let filter = AccentFilter::new()
+        .accent(ProseAccent::Atnach.into())
+        .accent(ProseAccent::Silluq.into());
+    assert_eq!(
+        sc.render_filtered(&filter),
+        "בְּרֵאשִׁית בָּרָא אֱלֹהִ֑ים אֵת הַשָּׁמַיִם וְאֵת הָאָֽרֶץ׃"
+    );
+}
+#[test]
+fn test_render_filtered_no_accents() {
+    let sc = SentenceContext::new("וַיְהִי־עֶ֖רֶב וַֽיְהִי־בֹ֖קֶר י֥וֹם אֶחָֽד׃", Context::Prosaic);
+    assert_eq!(
+        sc.render_filtered(&AccentFilter::new()),
+        "וַיְהִי־עֶרֶב וַיְהִי־בֹקֶר יוֹם אֶחָד׃"
+    );
+    let filter = AccentFilter::new()
+        .pseudo_accents(false)
+        .vowels(false)
+        .points(false);
+    assert_eq!(sc.render_filtered(&filter), "ויהיערב ויהיבקר יום אחד");
+}
+#[test]
+fn test_render_filtered_two_code_points() {
+    // Legarmeh is Munach followed by Paseq, both are dropped
+    let sc = SentenceContext::new("בּראשׁית בּ֣רא א֣להים׀ את", Context::Prosaic);
+    let filter = AccentFilter::new().accent(ProseAccent::Munach.into());
+    assert_eq!(sc.render_filtered(&filter), "בּראשׁית בּ֣רא אלהים את");
+    let filter = AccentFilter::new().accent(ProseAccent::Legarmeh.into());
+    assert_eq!(sc.render_filtered(&filter), "בּראשׁית בּרא א֣להים׀ את");
+}
+#[test]
+fn test_render_filtered_accent_type() {
+    let sc = SentenceContext::new("וַֽיְהִי־בֹ֖קֶר", Context::Prosaic);
+    let filter = AccentFilter::new().accent_type(AccentType::Secondary);
+    assert_eq!(sc.render_filtered(&filter), "וַֽיְהִי־בֹקֶר");
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "serde")]
+
+use hebrew_accents::{
+    Accent, Book, Context, HebrewAccent, PoetryAccent, ProseAccent, PseudoAccent, Reference,
+    SentenceContext,
+};
+
+#[test]
+fn test_serde_accent_names() {
+    let accent: HebrewAccent = ProseAccent::ZaqephQaton.into();
+    let json = serde_json::to_string(&accent).unwrap();
+    assert_eq!(json, r#"{"Prose":"Zaqeph Qaton"}"#);
+    assert_eq!(serde_json::from_str::<HebrewAccent>(&json).unwrap(), accent);
+    // any name of the accent is accepted
+    let accent: ProseAccent = serde_json::from_str(r#""Etnachta""#).unwrap();
+    assert_eq!(accent, ProseAccent::Atnach);
+    assert!(serde_json::from_str::<ProseAccent>(r#""Oleh We Yored""#).is_err());
+}
+#[test]
+fn test_serde_roundtrip_all_accents() {
+    let accents = ProseAccent::ALL
+        .map(HebrewAccent::from)
+        .into_iter()
+        .chain(PoetryAccent::ALL.map(HebrewAccent::from))
+        .chain(PseudoAccent::ALL.map(HebrewAccent::from));
+    for accent in accents {
+        let json = serde_json::to_string(&accent).unwrap();
+        assert_eq!(
+            serde_json::from_str::<HebrewAccent>(&json).unwrap(),
+            accent,
+            "{}",
+            json
+        );
+    }
+}
+#[test]
+fn test_serde_sentence_context() {
+    let sc = SentenceContext::new("בָּרָ֣א אֱלֹהִ֑ים", Context::Poetic);
+    let json = serde_json::to_string(&sc).unwrap();
+    assert_eq!(json, r#"{"sentence":"בָּרָ֣א אֱלֹהִ֑ים","ctx":"Poetic"}"#);
+    assert_eq!(serde_json::from_str::<SentenceContext>(&json).unwrap(), sc);
+}
+#[test]
+fn test_serde_match_and_info() {
+    let sc = SentenceContext::new("בָּרָ֣א אֱלֹהִ֑ים", Context::Prosaic);
+    let found = sc.find_accent(ProseAccent::Atnach.into()).unwrap();
+    let value = serde_json::to_value(found).unwrap();
+    assert_eq!(value["accent"]["Prose"], "Atnach");
+    assert_eq!(value["text"], found.as_str());
+    assert_eq!(value["start"], found.start());
+    let value = serde_json::to_value(ProseAccent::Atnach.details()).unwrap();
+    assert_eq!(value["category"], "Disjunctive");
+    assert_eq!(value["code_points"]["primary"]["code_point"], "U+0591");
+    assert_eq!(
+        value["code_points"]["primary"]["traditions"][0]["Ashkenazi"]["english_name"],
+        "Etnachta"
+    );
+}
+#[test]
+fn test_serde_reference() {
+    let reference = Reference::new(Book::Job, 3, 2).unwrap();
+    let json = serde_json::to_string(&reference).unwrap();
+    assert_eq!(json, r#"{"book":"Job","chapter":3,"verse":2}"#);
+    assert_eq!(serde_json::from_str::<Reference>(&json).unwrap(), reference);
+}
@@ -0,0 +1,115 @@
+use hebrew_accents::{
+    AccentCategory, Context, HebrewAccent, PoetryAccent, ProseAccent, SentenceContext, Statistics,
+    TableFormat,
+};
+
+const VERSES: [&str; 3] = [
+    "בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים אֵ֥ת הַשָּׁמַ֖יִם וְאֵ֥ת הָאָֽרֶץ׃",
+    "וַיֹּ֥אמֶר אֱלֹהִ֖ים יְהִ֣י א֑וֹר וַֽיְהִי־אֽוֹר׃",
+    "וַיַּ֧רְא אֱלֹהִ֛ים אֶת־הָא֖וֹר כִּי־ט֑וֹב",
+];
+
+fn prose(accent: ProseAccent) -> HebrewAccent {
+    accent.into()
+}
+
+fn collect(verses: &[&str]) -> Statistics {
+    let verses: Vec<SentenceContext> = verses
+        .iter()
+        .map(|verse| SentenceContext::new(verse, Context::Prosaic))
+        .collect();
+    verses.iter().collect()
+}
+
+#[test]
+fn test_statistics_counts() {
+    let stats = collect(&VERSES);
+    assert_eq!(stats.verses, 3);
+    assert_eq!(stats.count(prose(ProseAccent::Atnach)), 3);
+    assert_eq!(stats.count(prose(ProseAccent::Silluq)), 2);
+    assert_eq!(stats.count(prose(ProseAccent::Revia)), 0);
+    assert_eq!(stats.most_frequent()[0].0, prose(ProseAccent::Tiphcha));
+    let categories: usize = stats.categories.values().sum();
+    assert!(categories <= stats.total());
+    assert!(stats.categories[&AccentCategory::Disjunctive] > 0);
+    assert!(stats.categories[&AccentCategory::Conjunctive] > 0);
+}
+#[test]
+fn test_statistics_sequences() {
+    let stats = collect(&VERSES[..1]);
+    let tiphcha = prose(ProseAccent::Tiphcha);
+    let munach = prose(ProseAccent::Munach);
+    let atnach = prose(ProseAccent::Atnach);
+    let merkha = prose(ProseAccent::Merkha);
+    assert_eq!(stats.bigrams[&[munach, atnach]], 1);
+    let silluq = prose(ProseAccent::Silluq);
+    assert_eq!(stats.bigrams[&[merkha, tiphcha]], 1);
+    assert_eq!(stats.bigrams[&[merkha, silluq]], 1);
+    assert_eq!(stats.bigrams.values().sum::<usize>(), 6);
+    assert_eq!(stats.trigrams[&[tiphcha, munach, atnach]], 1);
+    // counted once per verse, the smaller accent first
+    let pair = if merkha < tiphcha {
+        (merkha, tiphcha)
+    } else {
+        (tiphcha, merkha)
+    };
+    assert_eq!(stats.co_occurrences[&pair], 1);
+    assert!(stats.co_occurrences.keys().all(|(a, b)| a < b));
+}
+#[test]
+fn test_statistics_positions() {
+    let stats = collect(&VERSES[..2]);
+    let silluq = prose(ProseAccent::Silluq);
+    assert_eq!(stats.positions[&silluq].len(), 1);
+    assert_eq!(stats.positions[&silluq][&0], 2);
+    // the words of a Maqqeph group are counted one by one
+    assert_eq!(stats.positions[&prose(ProseAccent::Atnach)][&2], 1);
+}
+#[test]
+fn test_statistics_merge() {
+    let mut stats = collect(&VERSES[..1]);
+    stats.merge(collect(&VERSES[1..]));
+    assert_eq!(stats, collect(&VERSES));
+}
+#[test]
+fn test_statistics_poetry_revia_mugrash() {
+    // Ps 23:1
+    let sc = SentenceContext::new("מִזְמ֥וֹר לְדָוִ֑ד יְהֹוָ֥ה רֹ֝עִ֗י לֹ֣א אֶחְסָֽר׃", Context::Poetic);
+    let mut stats = Statistics::new();
+    stats.add(&sc);
+    assert_eq!(stats.count(PoetryAccent::ReviaMugrash.into()), 1);
+    assert_eq!(stats.count(PoetryAccent::ReviaGadol.into()), 0);
+    assert_eq!(stats.positions[&PoetryAccent::ReviaMugrash.into()][&2], 1);
+}
+#[test]
+fn test_statistics_write() {
+    let stats = collect(&VERSES);
+    let mut json = Vec::new();
+    stats.write(&mut json, TableFormat::Json).unwrap();
+    let value: serde_json::Value = serde_json::from_slice(&json).unwrap();
+    let rows = value.as_array().unwrap();
+    assert_eq!(rows[0]["statistic"], "verses");
+    assert_eq!(rows[0]["count"], 3);
+    assert!(rows.iter().any(|row| row["statistic"] == "position"
+        && row["key"] == "prose:Silluq"
+        && row["position"] == 0
+        && row["count"] == 2));
+    let mut markdown = Vec::new();
+    stats.write(&mut markdown, TableFormat::Markdown).unwrap();
+    let markdown = String::from_utf8(markdown).unwrap();
+    assert!(markdown.starts_with("| statistic | key | position | count |\n|---|---|---|---|\n"));
+    assert!(markdown.contains("| accent | prose:Atnach |  | 3 |\n"));
+}
+#[test]
+fn test_statistics_write_prose_and_poetry() {
+    // the prose and the poetry Atnach have the same name
+    let mut stats = Statistics::new();
+    stats.add(&SentenceContext::new("בָּרָ֣א אֱלֹהִ֑ים", Context::Prosaic));
+    stats.add(&SentenceContext::new("מִזְמ֥וֹר לְדָוִ֑ד", Context::Poetic));
+    let mut csv = Vec::new();
+    stats.write(&mut csv, TableFormat::Csv).unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+    assert!(csv.contains("\naccent,prose:Atnach,,1\n"));
+    assert!(csv.contains("\naccent,poetry:Atnach,,1\n"));
+    assert!(csv.contains("\nbigram,poetry:Merkha > poetry:Atnach,,1\n"));
+}
@@ -0,0 +1,72 @@
+use hebrew_accents::{Context, SentenceContext};
+
+/// The syllables of every word in the sentence
+fn syllables(sentence: &str) -> Vec<Vec<String>> {
+    SentenceContext::new(sentence, Context::Prosaic)
+        .words()
+        .iter()
+        .map(|word| {
+            word.syllables()
+                .iter()
+                .map(|s| s.as_str().to_string())
+                .collect()
+        })
+        .collect()
+}
+
+/// The stressed syllable of every word in the sentence
+fn stressed(sentence: &str) -> Vec<String> {
+    SentenceContext::new(sentence, Context::Prosaic)
+        .words()
+        .iter()
+        .map(|word| word.stressed_syllable().unwrap().as_str().to_string())
+        .collect()
+}
+
+#[test]
+fn test_syllables_shewa_and_vowel_letters() {
+    // vocal Shewa at the start, vowel letters Alef and Yod
+    assert_eq!(syllables("בְּרֵאשִׁ֖ית"), vec![vec!["בְּרֵא", "שִׁ֖ית"]]);
+    // silent Shewa closes the syllable, Dagesh Forte
+    let sc = SentenceContext::new("וַיַּ֥רְא", Context::Prosaic);
+    let found = sc.words()[0].syllables();
+    assert_eq!(found.len(), 2);
+    assert_eq!(found[0].as_str(), "וַ");
+    assert_eq!(found[1].as_str(), "יַּ֥רְא");
+    assert!(!found[0].open);
+    assert!(!found[1].open);
+    // silent Shewa followed by a vocal Shewa
+    assert_eq!(syllables("יִשְׁמְרוּ"), vec![vec!["יִשְׁ", "מְרוּ"]]);
+}
+#[test]
+fn test_syllables_holem_male_shureq_furtive_patach() {
+    // Holem Male
+    assert_eq!(syllables("הָאוֹר"), vec![vec!["הָ", "אוֹר"]]);
+    // Shureq and Furtive Patach
+    assert_eq!(syllables("ר֫וּחַ"), vec![vec!["ר֫וּחַ"]]);
+    // conjunction Waw as Shureq, Hateph vowel
+    assert_eq!(syllables("וּמֵאֱלֹהִים"), vec![vec!["וּ", "מֵ", "אֱלֹ", "הִים"]]);
+}
+#[test]
+fn test_stress_impositive() {
+    // the Atnach marks the penultimate syllable of a Segolate
+    assert_eq!(stressed("בָּרָ֣א אֱלֹהִ֑ים הָאָ֑רֶץ"), vec!["רָ֣א", "הִ֑ים", "אָ֑"]);
+}
+#[test]
+fn test_stress_postpositive_and_prepositive() {
+    // double Pashta marks the stressed syllable
+    assert_eq!(stressed("תֹ֙הוּ֙"), vec!["תֹ֙"]);
+    // single Pashta at the end of the word, stress on the last syllable
+    assert_eq!(stressed("הָאוֹר֙"), vec!["אוֹר֙"]);
+    // Segolta on a Segolate, stress on the penultimate syllable
+    assert_eq!(stressed("מֶלֶךְ֒"), vec!["מֶ"]);
+    // prepositive Telisha Gedolah
+    assert_eq!(stressed("֠הַמֶּלֶךְ"), vec!["מֶּ"]);
+}
+#[test]
+fn test_stress_two_code_points() {
+    // the Revia of a Revia Mugrash marks the stressed syllable, not the Geresh Muqdam
+    let sc = SentenceContext::new("וּ֝מֶ֗לֶךְ", Context::Poetic);
+    let words = sc.words();
+    assert_eq!(words[0].stressed_syllable().unwrap().as_str(), "מֶ֗");
+}
@@ -0,0 +1,155 @@
+use hebrew_accents::{
+    Context, Error, PoetryAccent, ProseAccent, PseudoAccent, SentenceContext, Statistics,
+};
+
+#[test]
+fn test_try_contains_accent_ok() {
+    let sc = SentenceContext::new(
+        "ויּ֣ר֖א עשׂ֔ו כּ֥י רע֖ות נ֣ות כּ֖נ֑ען בּעינ֖י יצח֥ק א֖בֽיו׃",
+        Context::Prosaic,
+    );
+    assert_eq!(
+        sc.try_contains_accent(ProseAccent::Tiphcha.into()),
+        Ok(true)
+    );
+    assert_eq!(
+        sc.try_contains_accent(ProseAccent::Segolta.into()),
+        Ok(false)
+    );
+    // the result equals contains_accent()
+    assert_eq!(
+        sc.try_contains_accent(ProseAccent::Silluq.into()),
+        Ok(sc.contains_accent(ProseAccent::Silluq.into()))
+    );
+    // pseudo accents apply to every context
+    assert_eq!(
+        sc.try_contains_accent(PseudoAccent::Maqqeph.into()),
+        Ok(false)
+    );
+}
+#[test]
+fn test_try_find_accent_ok() {
+    let sc = SentenceContext::new("יצחק אל־יע֓קב ׀ ויברך", Context::Poetic);
+    let found = sc
+        .try_find_accent(PoetryAccent::ShalsheletGadol.into())
+        .unwrap();
+    assert_eq!(found, sc.find_accent(PoetryAccent::ShalsheletGadol.into()));
+    assert!(found.is_some());
+    assert_eq!(sc.try_find_accent(PoetryAccent::Dechi.into()), Ok(None));
+}
+#[test]
+fn test_try_accent_context_mismatch() {
+    let sc = SentenceContext::new("ויּ֣ר֖א עשׂ֔ו כּ֥י רע֖ות׃", Context::Poetic);
+    let expected = Err(Error::ContextMismatch {
+        accent: ProseAccent::ZaqephQaton.into(),
+        ctx: Context::Poetic,
+    });
+    assert_eq!(
+        sc.try_contains_accent(ProseAccent::ZaqephQaton.into()),
+        expected
+    );
+    assert_eq!(
+        sc.try_find_accent(ProseAccent::ZaqephQaton.into()),
+        Err(Error::ContextMismatch {
+            accent: ProseAccent::ZaqephQaton.into(),
+            ctx: Context::Poetic,
+        })
+    );
+    let sc = SentenceContext::new("אַ֭שְׁרֵי", Context::Prosaic);
+    assert!(matches!(
+        sc.try_contains_accent(PoetryAccent::Dechi.into()),
+        Err(Error::ContextMismatch { .. })
+    ));
+}
+#[test]
+fn test_try_accent_agrees_with_contains_accent() {
+    // accents shared by both systems only apply to their own context
+    let sc = SentenceContext::new("בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים אֵ֥ת הַשָּׁמַ֖יִם וְאֵ֥ת הָאָֽרֶץ׃", Context::Poetic);
+    for accent in [
+        ProseAccent::Silluq.into(),
+        ProseAccent::Atnach.into(),
+        ProseAccent::Tiphcha.into(),
+        ProseAccent::Legarmeh.into(),
+    ] {
+        assert!(matches!(
+            sc.try_contains_accent(accent),
+            Err(Error::ContextMismatch { .. })
+        ));
+        assert!(!sc.contains_accent(accent));
+        assert_eq!(sc.find_accent(accent), None);
+    }
+    let sc = SentenceContext::new("בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים אֵ֥ת הַשָּׁמַ֖יִם וְאֵ֥ת הָאָֽרֶץ׃", Context::Prosaic);
+    for accent in [
+        PoetryAccent::Silluq.into(),
+        PoetryAccent::Atnach.into(),
+        PoetryAccent::Tarcha.into(),
+    ] {
+        assert!(matches!(
+            sc.try_contains_accent(accent),
+            Err(Error::ContextMismatch { .. })
+        ));
+        assert!(!sc.contains_accent(accent));
+        assert_eq!(sc.find_iter(accent).count(), 0);
+    }
+}
+#[test]
+fn test_try_accent_backtrack_limit_exceeded() {
+    // a very long sentence exceeds the backtrack limit of the Munach regex
+    let sc = SentenceContext::new(&"א ".repeat(1_000_000), Context::Prosaic);
+    let munach = ProseAccent::Munach.into();
+    assert_eq!(
+        sc.try_contains_accent(munach),
+        Err(Error::BacktrackLimitExceeded)
+    );
+    assert_eq!(
+        sc.try_find_accent(munach),
+        Err(Error::BacktrackLimitExceeded)
+    );
+    assert!(matches!(
+        sc.try_find_iter(munach),
+        Err(Error::BacktrackLimitExceeded)
+    ));
+    assert_eq!(sc.try_analyze(), Err(Error::BacktrackLimitExceeded));
+    assert!(matches!(sc.try_words(), Err(Error::BacktrackLimitExceeded)));
+    let mut stats = Statistics::new();
+    assert_eq!(stats.try_add(&sc), Err(Error::BacktrackLimitExceeded));
+    assert_eq!(stats.verses, 0);
+}
+#[test]
+#[should_panic(expected = "BacktrackLimitExceeded")]
+fn test_contains_accent_backtrack_limit_exceeded_panics() {
+    let sc = SentenceContext::new(&"א ".repeat(1_000_000), Context::Prosaic);
+    sc.contains_accent(ProseAccent::Munach.into());
+}
+#[test]
+fn test_error_from_fancy_regex() {
+    let err = fancy_regex::Error::RuntimeError(fancy_regex::RuntimeError::BacktrackLimitExceeded);
+    assert_eq!(Error::from(err), Error::BacktrackLimitExceeded);
+    // any other error keeps the message of the regex
+    let err = fancy_regex::Regex::new("(").unwrap_err();
+    let message = err.to_string();
+    assert_eq!(Error::from(err), Error::Regex(message));
+}
+#[test]
+fn test_try_accent_invalid_input() {
+    let sc = SentenceContext::new(" \t", Context::Prosaic);
+    assert!(matches!(
+        sc.try_contains_accent(ProseAccent::Atnach.into()),
+        Err(Error::InvalidInput(_))
+    ));
+    assert!(matches!(
+        sc.try_find_accent(PseudoAccent::SophPasuq.into()),
+        Err(Error::InvalidInput(_))
+    ));
+}
+#[test]
+fn test_error_display() {
+    assert_eq!(
+        Error::BacktrackLimitExceeded.to_string(),
+        "backtrack limit exceeded while searching the sentence"
+    );
+    assert_eq!(
+        Error::InvalidInput("the sentence is empty".to_string()).to_string(),
+        "invalid input: the sentence is empty"
+    );
+}
@@ -0,0 +1,66 @@
+use hebrew_accents::{Accent, Context, HebrewAccent, ProseAccent, SentenceContext};
+
+#[test]
+fn test_validate_servi_gen_1_1() {
+    let sc = SentenceContext::new("בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים אֵ֥ת הַשָּׁמַ֖יִם וְאֵ֥ת הָאָֽרֶץ׃", Context::Prosaic);
+    assert!(sc.validate_servi().is_empty());
+}
+#[test]
+fn test_validate_servi_unexpected() {
+    // Mahpakh before Atnach, Munach before Silluq
+    let sc = SentenceContext::new("בְּרֵאשִׁ֖ית בָּרָ֤א אֱלֹהִ֑ים אֵ֥ת הַשָּׁמַ֖יִם וְאֵ֣ת הָאָֽרֶץ׃", Context::Prosaic);
+    let diagnostics = sc.validate_servi();
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(diagnostics[0].accent, ProseAccent::Atnach.into());
+    assert_eq!(diagnostics[0].servi, vec![ProseAccent::Mahpakh.into()]);
+    assert_eq!(diagnostics[0].as_str(), "בָּרָ֤א");
+    assert_eq!(
+        diagnostics[0].to_string(),
+        format!(
+            "unexpected servi Mahpakh before Atnach at {}..{}",
+            diagnostics[0].start(),
+            diagnostics[0].end()
+        )
+    );
+    assert_eq!(diagnostics[1].accent, ProseAccent::Silluq.into());
+    assert_eq!(diagnostics[1].as_str(), "וְאֵ֣ת");
+}
+#[test]
+fn test_validate_servi_empty() {
+    let sc = SentenceContext::new("", Context::Prosaic);
+    assert!(sc.validate_servi().is_empty());
+}
+#[test]
+fn test_validate_servi_gen_1() {
+    // real verses, every servus sequence is expected
+    for verse in [
+        // Gen 1:2
+        "וְהָאָ֗רֶץ הָיְתָ֥ה תֹ֙הוּ֙ וָבֹ֔הוּ וְחֹ֖שֶׁךְ עַל־פְּנֵ֣י תְה֑וֹם וְר֣וּחַ אֱלֹהִ֔ים מְרַחֶ֖פֶת עַל־פְּנֵ֥י הַמָּֽיִם׃",
+        // Gen 1:4
+        "וַיַּ֧רְא אֱלֹהִ֛ים אֶת־הָא֖וֹר כִּי־ט֑וֹב וַיַּבְדֵּ֣ל אֱלֹהִ֔ים בֵּ֥ין הָא֖וֹר וּבֵ֥ין הַחֹֽשֶׁךְ׃",
+        // Gen 1:7
+        "וַיַּ֣עַשׂ אֱלֹהִים֮ אֶת־הָרָקִיעַ֒ וַיַּבְדֵּ֗ל בֵּ֤ין הַמַּ֙יִם֙ אֲשֶׁר֙ מִתַּ֣חַת לָרָקִ֔יעַ וּבֵ֣ין הַמַּ֔יִם אֲשֶׁ֖ר מֵעַ֣ל לָרָקִ֑יעַ וַֽיְהִי־כֵֽן׃",
+        // Gen 1:11, Munach before Gershayim
+        "וַיֹּ֣אמֶר אֱלֹהִ֗ים תַּֽדְשֵׁ֤א הָאָ֨רֶץ֙ דֶּ֔שֶׁא עֵ֚שֶׂב מַזְרִ֣יעַ זֶ֔רַע עֵ֣ץ פְּרִ֞י עֹ֤שֶׂה פְּרִי֙ לְמִינ֔וֹ אֲשֶׁ֥ר זַרְעוֹ־ב֖וֹ עַל־הָאָ֑רֶץ וַֽיְהִי־כֵֽן׃",
+        // Gen 1:12, Munach and Mahpakh before Pashta
+        "וַתּוֹצֵ֨א הָאָ֜רֶץ דֶּ֠שֶׁא עֵ֣שֶׂב מַזְרִ֤יעַ זֶ֙רַע֙ לְמִינֵ֔הוּ וְעֵ֧ץ עֹֽשֶׂה־פְּרִ֛י אֲשֶׁ֥ר זַרְעוֹ־ב֖וֹ לְמִינֵ֑הוּ וַיַּ֥רְא אֱלֹהִ֖ים כִּי־טֽוֹב׃",
+        // Gen 1:21, two Munach's before Legarmeh, Telisha Qetannah and Azla before Geresh
+        "וַיִּבְרָ֣א אֱלֹהִ֔ים אֶת־הַתַּנִּינִ֖ם הַגְּדֹלִ֑ים וְאֵ֣ת כָּל־נֶ֣פֶשׁ הַֽחַיָּ֣ה ׀ הָֽרֹמֶ֡שֶׂת אֲשֶׁר֩ שָׁרְצ֨וּ הַמַּ֜יִם לְמִֽינֵהֶ֗ם וְאֵ֨ת כָּל־ע֤וֹף כָּנָף֙ לְמִינֵ֔הוּ וַיַּ֥רְא אֱלֹהִ֖ים כִּי־טֽוֹב׃",
+    ] {
+        let sc = SentenceContext::new(verse, Context::Prosaic);
+        assert!(sc.validate_servi().is_empty(), "{verse}");
+    }
+}
+#[test]
+fn test_servi_alternatives() {
+    let servi = ProseAccent::Gershayim.servi().unwrap();
+    let munach: HebrewAccent = ProseAccent::Munach.into();
+    let azla: HebrewAccent = ProseAccent::Azla.into();
+    assert!(servi.allows(&[munach]));
+    assert!(servi.allows(&[munach, azla]));
+    assert!(!servi.allows(&[azla, munach]));
+    // Darga only precedes the Merkha of a Tiphcha
+    let servi = ProseAccent::Tiphcha.servi().unwrap();
+    assert!(servi.allows(&[ProseAccent::Merkha.into()]));
+    assert!(!servi.allows(&[ProseAccent::Darga.into()]));
+}
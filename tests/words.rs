@@ -0,0 +1,88 @@
+use hebrew_accents::{
+    Context, HebrewAccent, PoetryAccent, ProseAccent, SectionMarker, SentenceContext, Token,
+};
+
+#[test]
+fn test_tokens_gen_1_5() {
+    let sc = SentenceContext::new("וַיְהִי־עֶ֖רֶב וַֽיְהִי־בֹ֖קֶר י֥וֹם אֶחָֽד׃ פ", Context::Prosaic);
+    let tokens = sc.tokens();
+    assert_eq!(tokens.len(), 6);
+    assert!(matches!(tokens[4], Token::SophPasuq(_)));
+    assert!(matches!(
+        tokens[5],
+        Token::SectionMarker(SectionMarker::Petuchah, _)
+    ));
+    // the tokens are ordered and slice the sentence
+    assert!(tokens
+        .windows(2)
+        .all(|w| w[0].range().end <= w[1].range().start));
+    assert_eq!(&sc.sentence[tokens[4].range()], "׃");
+}
+#[test]
+fn test_word_groups_and_words() {
+    let sc = SentenceContext::new("וַיְהִי־עֶ֖רֶב וַֽיְהִי־בֹ֖קֶר י֥וֹם אֶחָֽד׃ ס", Context::Prosaic);
+    let groups = sc.word_groups();
+    assert_eq!(groups.len(), 4);
+    assert_eq!(groups[1].as_str(), "וַֽיְהִי־בֹ֖קֶר");
+    assert_eq!(
+        groups[1]
+            .words()
+            .iter()
+            .map(|w| w.as_str())
+            .collect::<Vec<_>>(),
+        vec!["וַֽיְהִי", "בֹ֖קֶר"]
+    );
+    assert_eq!(
+        groups[1].accents(),
+        vec![ProseAccent::Meteg.into(), ProseAccent::Tiphcha.into()]
+    );
+    // the Silluq is in the last word
+    let words = sc.words();
+    assert_eq!(words.len(), 6);
+    assert_eq!(words[5].as_str(), "אֶחָֽד");
+    assert_eq!(words[5].accents(), vec![ProseAccent::Silluq.into()]);
+    // the Samekh is a section marker
+    assert!(matches!(
+        sc.tokens().last(),
+        Some(Token::SectionMarker(SectionMarker::Setumah, _))
+    ));
+}
+#[test]
+fn test_tokens_paseq() {
+    // Paseq attached to the word and separated by a space
+    let sc = SentenceContext::new("בּראשׁית בּ֣רא א֣להים׀ את | השּׁמים", Context::Prosaic);
+    let tokens = sc.tokens();
+    assert_eq!(tokens.len(), 7);
+    assert!(matches!(tokens[3], Token::Paseq(_)));
+    assert!(matches!(tokens[5], Token::Paseq(_)));
+    let words = sc.words();
+    assert_eq!(words[2].as_str(), "א֣להים");
+    // Munach followed by Paseq is a Legarmeh, assigned to the word
+    assert_eq!(words[2].accents(), vec![ProseAccent::Legarmeh.into()]);
+    assert_eq!(words[1].accents(), vec![ProseAccent::Munach.into()]);
+}
+#[test]
+fn test_tokens_poetry() {
+    // Tsinnorit Merkha belongs to the word of the Tsinnorit
+    let sc = SentenceContext::new("בּראשׁית בּר֥א א֘להי֥ם א֥ת", Context::Poetic);
+    let accents: Vec<Vec<HebrewAccent>> = sc.words().iter().map(|w| w.accents()).collect();
+    assert_eq!(
+        accents,
+        vec![
+            vec![],
+            vec![PoetryAccent::Merkha.into()],
+            vec![PoetryAccent::TsinnoritMerkha.into()],
+            vec![PoetryAccent::Merkha.into()],
+        ]
+    );
+}
+#[test]
+fn test_tokens_no_section_marker() {
+    // a single Pe before the Sof Pasuq is not a section marker
+    let sc = SentenceContext::new("פ אֶחָֽד׃", Context::Prosaic);
+    let tokens = sc.tokens();
+    assert!(matches!(tokens[0], Token::WordGroup(_)));
+    assert!(SentenceContext::new("", Context::Prosaic)
+        .tokens()
+        .is_empty());
+}